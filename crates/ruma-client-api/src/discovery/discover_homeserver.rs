@@ -169,3 +169,26 @@ impl SlidingSyncProxyInfo {
         Self { url }
     }
 }
+
+#[cfg(all(test, feature = "client"))]
+mod tests {
+    use ruma_common::api::IncomingResponse;
+
+    use super::Response;
+
+    #[test]
+    fn response_with_any_content_type() {
+        for content_type in ["application/json", "text/plain", "application/octet-stream"] {
+            let http_res = http::Response::builder()
+                .header(http::header::CONTENT_TYPE, content_type)
+                .body(
+                    br#"{ "m.homeserver": { "base_url": "https://matrix.example.org" } }"#
+                        as &[u8],
+                )
+                .unwrap();
+
+            let res = Response::try_from_http_response(http_res).unwrap();
+            assert_eq!(res.homeserver.base_url, "https://matrix.example.org");
+        }
+    }
+}
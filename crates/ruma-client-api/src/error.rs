@@ -281,6 +281,19 @@ pub struct Error {
     pub body: ErrorBody,
 }
 
+impl Error {
+    /// The time to wait before retrying the request, if this is an `M_LIMIT_EXCEEDED` error that
+    /// specifies it.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match &self.body {
+            ErrorBody::Standard { kind: ErrorKind::LimitExceeded { retry_after_ms }, .. } => {
+                *retry_after_ms
+            }
+            _ => None,
+        }
+    }
+}
+
 impl EndpointError for Error {
     fn from_http_response<T: AsRef<[u8]>>(response: http::Response<T>) -> Self {
         let status = response.status();
@@ -468,9 +481,12 @@ impl TryFrom<&AuthenticateError> for http::HeaderValue {
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
+    use ruma_common::api::EndpointError;
     use serde_json::{from_value as from_json_value, json};
 
-    use super::{ErrorKind, StandardErrorBody};
+    use super::{Error, ErrorKind, StandardErrorBody};
 
     #[test]
     fn deserialize_forbidden() {
@@ -484,6 +500,28 @@ mod tests {
         assert_eq!(deserialized.message, "You are not authorized to ban users in this room.");
     }
 
+    #[test]
+    fn limit_exceeded_retry_after() {
+        let response = http::Response::builder()
+            .status(http::StatusCode::TOO_MANY_REQUESTS)
+            .body(
+                br#"{"errcode":"M_LIMIT_EXCEEDED","error":"Too many requests","retry_after_ms":2000}"#
+                    as &[u8],
+            )
+            .unwrap();
+
+        let error = Error::from_http_response(response);
+        assert_eq!(error.retry_after(), Some(Duration::from_millis(2000)));
+
+        let response = http::Response::builder()
+            .status(http::StatusCode::FORBIDDEN)
+            .body(br#"{"errcode":"M_FORBIDDEN","error":"Forbidden"}"# as &[u8])
+            .unwrap();
+
+        let error = Error::from_http_response(response);
+        assert_eq!(error.retry_after(), None);
+    }
+
     #[cfg(feature = "unstable-msc2967")]
     #[test]
     fn custom_authenticate_error_sanity() {
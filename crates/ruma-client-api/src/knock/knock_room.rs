@@ -11,6 +11,8 @@ pub mod v3 {
         api::{request, response, Metadata},
         metadata, OwnedRoomId, OwnedRoomOrAliasId, OwnedServerName,
     };
+    #[cfg(feature = "server")]
+    use ruma_common::{IdParseError, RoomOrAliasId};
 
     const METADATA: Metadata = metadata! {
         method: POST,
@@ -26,7 +28,7 @@ pub mod v3 {
     #[request(error = crate::Error)]
     pub struct Request {
         /// The room the user should knock on.
-        #[ruma_api(path)]
+        #[ruma_api(path, validate = "validate_room_id_or_alias")]
         pub room_id_or_alias: OwnedRoomOrAliasId,
 
         /// The reason for joining a room.
@@ -53,6 +55,33 @@ pub mod v3 {
         pub fn new(room_id_or_alias: OwnedRoomOrAliasId) -> Self {
             Self { room_id_or_alias, reason: None, server_name: vec![] }
         }
+
+        /// Creates a new `Request` with the given room ID or alias and the servers to knock
+        /// through.
+        pub fn with_servers(
+            room_id_or_alias: OwnedRoomOrAliasId,
+            server_name: Vec<OwnedServerName>,
+        ) -> Self {
+            Self { room_id_or_alias, reason: None, server_name }
+        }
+
+        /// Creates a new `Request` from `self` with the `reason` field set to the given value.
+        ///
+        /// Since the field is public, you can also assign to it directly. This method merely acts
+        /// as a shorthand for that, because it is very common to set this field.
+        pub fn reason(self, reason: impl Into<Option<String>>) -> Self {
+            Self { reason: reason.into(), ..self }
+        }
+    }
+
+    /// Rejects room IDs and room aliases with an empty localpart, which can't refer to a room.
+    #[cfg(feature = "server")]
+    fn validate_room_id_or_alias(room_id_or_alias: &RoomOrAliasId) -> Result<(), IdParseError> {
+        if room_id_or_alias.localpart().is_empty() {
+            return Err(IdParseError::Empty);
+        }
+
+        Ok(())
     }
 
     impl Response {
@@ -61,4 +90,100 @@ pub mod v3 {
             Self { room_id }
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        use ruma_common::{
+            api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+            room_alias_id, room_id, server_name,
+        };
+
+        use super::Request;
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_knock_room_request_uri() {
+            let req: http::Request<Vec<u8>> =
+                Request::new(room_id!("!room:localhost").to_owned().into())
+                    .try_into_http_request(
+                        "https://homeserver.tld",
+                        SendAccessToken::IfRequired("auth_tok"),
+                        &[MatrixVersion::V1_1],
+                    )
+                    .unwrap();
+            assert_eq!(req.uri().path(), "/_matrix/client/v3/knock/!room:localhost");
+
+            let req: http::Request<Vec<u8>> =
+                Request::new(room_id!("!room:localhost").to_owned().into())
+                    .try_into_http_request(
+                        "https://homeserver.tld",
+                        SendAccessToken::IfRequired("auth_tok"),
+                        &[MatrixVersion::V1_0],
+                    )
+                    .unwrap();
+            assert_eq!(
+                req.uri().path(),
+                "/_matrix/client/unstable/xyz.amorgan.knock/knock/!room:localhost"
+            );
+        }
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_knock_room_request_with_servers() {
+            let req: http::Request<Vec<u8>> = Request::with_servers(
+                room_alias_id!("#room:localhost").to_owned().into(),
+                vec![
+                    server_name!("example.org").to_owned(),
+                    server_name!("example.com").to_owned(),
+                ],
+            )
+            .reason("Let me in".to_owned())
+            .try_into_http_request(
+                "https://homeserver.tld",
+                SendAccessToken::IfRequired("auth_tok"),
+                &[MatrixVersion::V1_1],
+            )
+            .unwrap();
+
+            assert_eq!(req.uri().query(), Some("server_name=example.org&server_name=example.com"));
+            assert_eq!(
+                serde_json::from_slice::<serde_json::Value>(req.body()).unwrap(),
+                serde_json::json!({ "reason": "Let me in" })
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_knock_room_request_invalid_path() {
+            use assert_matches::assert_matches;
+            use ruma_common::{
+                api::{
+                    error::{DeserializationError, FromHttpRequestError},
+                    IncomingRequest,
+                },
+                IdParseError,
+            };
+
+            let req = http::Request::builder()
+                .method(http::Method::POST)
+                .uri("https://homeserver.tld/_matrix/client/v3/knock/%23:localhost")
+                .body(b"{}" as &[u8])
+                .unwrap();
+            assert_matches!(
+                Request::try_from_http_request(req, &["#:localhost"]),
+                Err(FromHttpRequestError::Deserialization(DeserializationError::Ident(
+                    IdParseError::Empty
+                )))
+            );
+
+            let req = http::Request::builder()
+                .method(http::Method::POST)
+                .uri("https://homeserver.tld/_matrix/client/v3/knock/%23room:localhost")
+                .body(b"{}" as &[u8])
+                .unwrap();
+            let request = Request::try_from_http_request(req, &["#room:localhost"]).unwrap();
+            assert_eq!(request.room_id_or_alias, "#room:localhost");
+        }
+    }
 }
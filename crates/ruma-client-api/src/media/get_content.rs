@@ -124,4 +124,29 @@ pub mod v3 {
             }
         }
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+        use ruma_common::api::IncomingResponse;
+
+        use super::Response;
+
+        #[test]
+        fn deserialize_response_headers() {
+            let res = Response::try_from_http_response(
+                http::Response::builder()
+                    .header(CONTENT_TYPE, "image/png")
+                    .header(CONTENT_DISPOSITION, "inline; filename=\"image.png\"")
+                    .body(b"PNG" as &[u8])
+                    .unwrap(),
+            )
+            .unwrap();
+
+            assert_eq!(res.file, b"PNG");
+            assert_eq!(res.content_type.as_deref(), Some("image/png"));
+            assert_eq!(res.content_disposition.as_deref(), Some("inline; filename=\"image.png\""));
+            assert_eq!(res.cross_origin_resource_policy, None);
+        }
+    }
 }
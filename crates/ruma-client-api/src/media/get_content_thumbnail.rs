@@ -11,12 +11,13 @@ pub mod v3 {
     use js_int::UInt;
     use ruma_common::{
         api::{request, response, Metadata},
-        metadata,
-        serde::StringEnum,
-        IdParseError, MxcUri, OwnedServerName,
+        metadata, IdParseError, MxcUri, OwnedServerName,
     };
 
-    use crate::{http_headers::CROSS_ORIGIN_RESOURCE_POLICY, PrivOwnedStr};
+    use crate::http_headers::CROSS_ORIGIN_RESOURCE_POLICY;
+
+    /// The desired resizing method.
+    pub use ruma_common::ThumbnailMethod as Method;
 
     const METADATA: Metadata = metadata! {
         method: GET,
@@ -142,20 +143,4 @@ pub mod v3 {
             }
         }
     }
-
-    /// The desired resizing method.
-    #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/doc/string_enum.md"))]
-    #[derive(Clone, StringEnum)]
-    #[ruma_enum(rename_all = "snake_case")]
-    #[non_exhaustive]
-    pub enum Method {
-        /// Crop the original to produce the requested image dimensions.
-        Crop,
-
-        /// Maintain the original aspect ratio of the source image.
-        Scale,
-
-        #[doc(hidden)]
-        _Custom(PrivOwnedStr),
-    }
 }
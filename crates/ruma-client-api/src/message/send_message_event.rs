@@ -27,7 +27,13 @@ pub mod v3 {
     };
 
     /// Request type for the `create_message_event` endpoint.
-    #[request(error = crate::Error)]
+    ///
+    /// Servers reject events larger than 65536 bytes, so requests with a larger body fail early
+    /// with [`IntoHttpError::BodyTooLarge`](ruma_common::api::error::IntoHttpError::BodyTooLarge).
+    /// This check is only approximate: the limit applies to the whole PDU built by the server,
+    /// which is larger than the content sent in this request, so a request with a smaller body
+    /// can still be rejected.
+    #[request(error = crate::Error, max_body = 65536)]
     pub struct Request {
         /// The room to send the event to.
         #[ruma_api(path)]
@@ -4,7 +4,7 @@ use std::{
 };
 
 use assign::assign;
-use async_stream::try_stream;
+use async_stream::{stream, try_stream};
 use futures_core::stream::Stream;
 use ruma_client_api::{
     account::register::{self, RegistrationKind},
@@ -13,13 +13,14 @@ use ruma_client_api::{
     uiaa::UserIdentifier,
 };
 use ruma_common::{
-    api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+    api::{MatrixVersion, OutgoingRequest},
     presence::PresenceState,
     DeviceId, UserId,
 };
 
 use crate::{
-    add_user_id_to_query, send_customized_request, Error, HttpClient, ResponseError, ResponseResult,
+    access_token_for, add_user_id_to_query, send_customized_request, Error, HttpClient,
+    ResponseError, ResponseResult,
 };
 
 mod builder;
@@ -79,15 +80,11 @@ impl<C: HttpClient> Client<C> {
         F: FnOnce(&mut http::Request<C::RequestBody>) -> Result<(), ResponseError<C, R>>,
     {
         let access_token = self.access_token();
-        let send_access_token = match access_token.as_deref() {
-            Some(at) => SendAccessToken::IfRequired(at),
-            None => SendAccessToken::None,
-        };
 
         send_customized_request(
             &self.0.http_client,
             &self.0.homeserver_url,
-            send_access_token,
+            access_token_for::<R>(access_token.as_deref()),
             &self.0.supported_matrix_versions,
             request,
             customize,
@@ -226,4 +223,37 @@ impl<C: HttpClient> Client<C> {
             }
         }
     }
+
+    /// Convenience method that represents repeated calls to the sync_events endpoint as a stream,
+    /// without ending the stream on errors.
+    ///
+    /// In contrast to [`sync`][Self::sync], errors are yielded as items of the stream and the
+    /// next request is sent with the same `since` token, so callers can decide whether to keep
+    /// polling the stream or to give up.
+    pub fn sync_stream(
+        &self,
+        filter: Option<sync_events::v3::Filter>,
+        mut since: String,
+        set_presence: PresenceState,
+        timeout: Option<Duration>,
+    ) -> impl Stream<Item = Result<sync_events::v3::Response, Error<C::Error, ruma_client_api::Error>>>
+           + '_ {
+        stream! {
+            loop {
+                let result = self
+                    .send_request(assign!(sync_events::v3::Request::new(), {
+                        filter: filter.clone(),
+                        since: Some(since.clone()),
+                        set_presence: set_presence.clone(),
+                        timeout,
+                    }))
+                    .await;
+
+                if let Ok(response) = &result {
+                    since = response.next_batch.clone();
+                }
+                yield result;
+            }
+        }
+    }
 }
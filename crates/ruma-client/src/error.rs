@@ -22,6 +22,9 @@ pub enum Error<E, F> {
 
     /// Converting the HTTP response to one of ruma's types failed.
     FromHttpResponse(FromHttpResponseError<F>),
+
+    /// The request didn't complete before the timeout.
+    Timeout,
 }
 
 impl<E: Display, F: Display> Display for Error<E, F> {
@@ -34,6 +37,7 @@ impl<E: Display, F: Display> Display for Error<E, F> {
             Self::Url(err) => write!(f, "Invalid URL: {err}"),
             Self::Response(err) => write!(f, "Couldn't obtain a response: {err}"),
             Self::FromHttpResponse(err) => write!(f, "HTTP response conversion failed: {err}"),
+            Self::Timeout => write!(f, "The request timed out."),
         }
     }
 }
@@ -1,34 +1,51 @@
 //! This module contains an abstraction for HTTP clients as well as friendly-named re-exports of
 //! client types that implement this trait.
 
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+use std::time::Duration;
 use std::{future::Future, pin::Pin};
 
 use async_trait::async_trait;
 use bytes::BufMut;
+use http::HeaderMap;
 use ruma_common::{
-    api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+    api::{AuthScheme, MatrixVersion, OutgoingRequest, SendAccessToken},
     UserId,
 };
 
-use crate::{add_user_id_to_query, ResponseError, ResponseResult};
+use crate::{access_token_for, add_user_id_to_query, Error, ResponseError, ResponseResult};
 
+#[cfg(any(feature = "gzip", feature = "deflate"))]
+mod decoding;
 #[cfg(feature = "hyper")]
 mod hyper;
 #[cfg(feature = "isahc")]
 mod isahc;
+#[cfg(feature = "mock")]
+mod mock;
 #[cfg(feature = "reqwest")]
 mod reqwest;
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+mod retry;
 
+#[cfg(any(feature = "gzip", feature = "deflate"))]
+pub use self::decoding::{DecodingError, DecodingHttpClient};
 #[cfg(feature = "hyper")]
 pub use self::hyper::Hyper;
 #[cfg(feature = "hyper-native-tls")]
 pub use self::hyper::HyperNativeTls;
 #[cfg(feature = "hyper-rustls")]
 pub use self::hyper::HyperRustls;
+#[cfg(all(unix, feature = "hyper-unix"))]
+pub use self::hyper::{HyperUnix, UnixConnection, UnixConnector};
 #[cfg(feature = "isahc")]
 pub use self::isahc::Isahc;
+#[cfg(feature = "mock")]
+pub use self::mock::{MockHttpClient, UnmatchedRequest};
 #[cfg(feature = "reqwest")]
 pub use self::reqwest::Reqwest;
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+pub use self::retry::RetryHttpClient;
 
 /// An HTTP client that can be used to send requests to a Matrix homeserver.
 #[async_trait]
@@ -78,6 +95,76 @@ pub trait HttpClientExt: HttpClient {
         )
     }
 
+    /// Send a strongly-typed matrix request to get back a strongly-typed response, failing with
+    /// [`Error::Timeout`] if the response isn't received before the given timeout.
+    ///
+    /// This uses the timer of the tokio runtime, so it is only available with the `hyper` or
+    /// `reqwest` features.
+    #[cfg(any(feature = "hyper", feature = "reqwest"))]
+    fn send_matrix_request_with_timeout<'a, R: OutgoingRequest + 'a>(
+        &'a self,
+        homeserver_url: &str,
+        access_token: SendAccessToken<'_>,
+        for_versions: &[MatrixVersion],
+        request: R,
+        timeout_duration: Duration,
+    ) -> Pin<Box<dyn Future<Output = ResponseResult<Self, R>> + 'a + Send>> {
+        let response =
+            self.send_matrix_request(homeserver_url, access_token, for_versions, request);
+
+        Box::pin(async move {
+            tokio::time::timeout(timeout_duration, response).await.unwrap_or(Err(Error::Timeout))
+        })
+    }
+
+    /// Send a strongly-typed matrix request to get back a strongly-typed response, only sending
+    /// the access token if the endpoint requires one.
+    ///
+    /// Fails early with [`Error::AuthenticationRequired`] if the endpoint requires an access token
+    /// but none was given, instead of sending the request.
+    fn send_matrix_request_auto_auth<'a, R: OutgoingRequest + 'a>(
+        &'a self,
+        homeserver_url: &str,
+        access_token: Option<&str>,
+        for_versions: &[MatrixVersion],
+        request: R,
+    ) -> Pin<Box<dyn Future<Output = ResponseResult<Self, R>> + 'a + Send>> {
+        if matches!(R::METADATA.authentication, AuthScheme::AccessToken) && access_token.is_none() {
+            return Box::pin(async { Err(Error::AuthenticationRequired) });
+        }
+
+        self.send_matrix_request(
+            homeserver_url,
+            access_token_for::<R>(access_token),
+            for_versions,
+            request,
+        )
+    }
+
+    /// Send a strongly-typed matrix request with additional HTTP headers to get back a
+    /// strongly-typed response.
+    ///
+    /// The given headers replace any header of the same name in the generated `http::Request`.
+    fn send_matrix_request_with_headers<'a, R: OutgoingRequest + 'a>(
+        &'a self,
+        homeserver_url: &str,
+        access_token: SendAccessToken<'_>,
+        for_versions: &[MatrixVersion],
+        request: R,
+        headers: HeaderMap,
+    ) -> Pin<Box<dyn Future<Output = ResponseResult<Self, R>> + 'a + Send>> {
+        self.send_customized_matrix_request(
+            homeserver_url,
+            access_token,
+            for_versions,
+            request,
+            move |http_request| {
+                http_request.headers_mut().extend(headers);
+                Ok(())
+            },
+        )
+    }
+
     /// Turn a strongly-typed matrix request into an `http::Request`, customize it and send it to
     /// get back a strongly-typed response.
     // TODO: `R: 'a` and `F: 'a` should not be needed
@@ -153,3 +240,25 @@ impl DefaultConstructibleHttpClient for Dummy {
         Dummy
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ruma_client_api::account::whoami;
+    use ruma_common::api::MatrixVersion;
+
+    use super::{Dummy, HttpClientExt};
+    use crate::Error;
+
+    #[tokio::test]
+    async fn auto_auth_missing_access_token() {
+        let res = Dummy
+            .send_matrix_request_auto_auth(
+                "https://homeserver.tld",
+                None,
+                &[MatrixVersion::V1_1],
+                whoami::v3::Request::new(),
+            )
+            .await;
+        assert!(matches!(res, Err(Error::AuthenticationRequired)));
+    }
+}
@@ -0,0 +1,296 @@
+use std::{
+    fmt,
+    io::{self, Read},
+};
+
+use async_trait::async_trait;
+use http::{
+    header::{HeaderValue, ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_LENGTH},
+    HeaderMap,
+};
+
+use super::HttpClient;
+
+/// The encodings supported by [`DecodingHttpClient`], as a value of the `Accept-Encoding` header.
+#[cfg(all(feature = "gzip", feature = "deflate"))]
+const SUPPORTED_ENCODINGS: &str = "gzip, deflate";
+#[cfg(all(feature = "gzip", not(feature = "deflate")))]
+const SUPPORTED_ENCODINGS: &str = "gzip";
+#[cfg(all(feature = "deflate", not(feature = "gzip")))]
+const SUPPORTED_ENCODINGS: &str = "deflate";
+
+/// An `HttpClient` that decodes compressed response bodies.
+///
+/// This client advertises the supported encodings in the `Accept-Encoding` header of the requests,
+/// unless it is already set, and decodes the body of the responses according to their
+/// `Content-Encoding` header. Encodings are matched case-insensitively, and several encodings
+/// applied one after the other are decoded in reverse order. Responses that are not encoded, or
+/// that use an unsupported encoding, are returned as-is.
+///
+/// To protect against decompression bombs, decoding fails with [`DecodingError::TooLarge`] if the
+/// decoded body is longer than the maximum length, which is
+/// [`DecodingHttpClient::DEFAULT_MAX_DECODED_LEN`] unless specified otherwise.
+///
+/// The supported encodings depend on the enabled features:
+///
+/// * `gzip`: the `gzip` encoding, and its `x-gzip` alias
+/// * `deflate`: the `deflate` encoding
+#[derive(Clone, Debug)]
+pub struct DecodingHttpClient<C> {
+    inner: C,
+    max_decoded_len: usize,
+}
+
+impl<C> DecodingHttpClient<C> {
+    /// The maximum length in bytes of a decoded body, if none is specified.
+    pub const DEFAULT_MAX_DECODED_LEN: usize = 64 * 1024 * 1024;
+
+    /// Creates a new `DecodingHttpClient` wrapping the given client.
+    pub fn new(inner: C) -> Self {
+        Self::with_max_decoded_len(inner, Self::DEFAULT_MAX_DECODED_LEN)
+    }
+
+    /// Creates a new `DecodingHttpClient` wrapping the given client, that fails to decode bodies
+    /// longer than `max_decoded_len` bytes.
+    pub fn with_max_decoded_len(inner: C, max_decoded_len: usize) -> Self {
+        Self { inner, max_decoded_len }
+    }
+
+    /// Get a reference to the inner client.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Get the inner client.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+#[async_trait]
+impl<C> HttpClient for DecodingHttpClient<C>
+where
+    C: HttpClient + Send,
+    C::RequestBody: Send,
+    C::ResponseBody: From<Vec<u8>> + Send,
+{
+    type RequestBody = C::RequestBody;
+    type ResponseBody = C::ResponseBody;
+    type Error = DecodingError<C::Error>;
+
+    async fn send_http_request(
+        &self,
+        mut req: http::Request<Self::RequestBody>,
+    ) -> Result<http::Response<Self::ResponseBody>, Self::Error> {
+        req.headers_mut()
+            .entry(ACCEPT_ENCODING)
+            .or_insert(HeaderValue::from_static(SUPPORTED_ENCODINGS));
+
+        let res = self.inner.send_http_request(req).await.map_err(DecodingError::Http)?;
+
+        let codings = match content_codings(res.headers()) {
+            Some(codings) => codings,
+            None => return Ok(res),
+        };
+        let (last, others) = match codings.split_last() {
+            Some(split) => split,
+            None => return Ok(res),
+        };
+
+        // The codings are listed in the order in which they were applied, so they are undone in
+        // reverse order.
+        let (mut parts, body) = res.into_parts();
+        let mut decoded = last.decode(body.as_ref(), self.max_decoded_len)?;
+        for coding in others.iter().rev() {
+            decoded = coding.decode(&decoded, self.max_decoded_len)?;
+        }
+
+        // The headers describing the encoded body don't apply to the decoded body.
+        parts.headers.remove(CONTENT_ENCODING);
+        parts.headers.remove(CONTENT_LENGTH);
+
+        Ok(http::Response::from_parts(parts, decoded.into()))
+    }
+}
+
+/// A content coding supported by [`DecodingHttpClient`].
+#[derive(Clone, Copy, Debug)]
+enum ContentCoding {
+    #[cfg(feature = "gzip")]
+    Gzip,
+
+    #[cfg(feature = "deflate")]
+    Deflate,
+}
+
+impl ContentCoding {
+    /// Get the content coding with the given case-insensitive name, if it is supported.
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            #[cfg(feature = "gzip")]
+            "gzip" | "x-gzip" => Some(Self::Gzip),
+            #[cfg(feature = "deflate")]
+            "deflate" => Some(Self::Deflate),
+            _ => None,
+        }
+    }
+
+    fn decode<E>(self, body: &[u8], max_len: usize) -> Result<Vec<u8>, DecodingError<E>> {
+        match self {
+            #[cfg(feature = "gzip")]
+            Self::Gzip => read_to_end(flate2::read::GzDecoder::new(body), max_len),
+            #[cfg(feature = "deflate")]
+            Self::Deflate => read_to_end(flate2::read::ZlibDecoder::new(body), max_len),
+        }
+    }
+}
+
+/// Get the content codings of a response from its `Content-Encoding` headers, in the order in
+/// which they were applied.
+///
+/// The `identity` coding is ignored. Returns `None` if one of the codings is not supported.
+fn content_codings(headers: &HeaderMap) -> Option<Vec<ContentCoding>> {
+    let mut codings = Vec::new();
+
+    for value in headers.get_all(CONTENT_ENCODING) {
+        for name in value.to_str().ok()?.split(',').map(str::trim) {
+            if name.is_empty() || name.eq_ignore_ascii_case("identity") {
+                continue;
+            }
+
+            codings.push(ContentCoding::from_name(name)?);
+        }
+    }
+
+    Some(codings)
+}
+
+fn read_to_end<E>(decoder: impl Read, max_len: usize) -> Result<Vec<u8>, DecodingError<E>> {
+    let mut body = Vec::new();
+
+    // Read one byte more than allowed, to detect bodies that are too long without decoding them
+    // entirely.
+    let limit = u64::try_from(max_len).unwrap_or(u64::MAX).saturating_add(1);
+    decoder.take(limit).read_to_end(&mut body).map_err(DecodingError::Decode)?;
+
+    if body.len() > max_len {
+        return Err(DecodingError::TooLarge { max_len });
+    }
+
+    Ok(body)
+}
+
+/// An error that can occur when sending a request with a [`DecodingHttpClient`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DecodingError<E> {
+    /// The inner client failed to obtain a response.
+    Http(E),
+
+    /// The body of the response couldn't be decoded.
+    Decode(io::Error),
+
+    /// The decoded body of the response is longer than the maximum length.
+    TooLarge {
+        /// The maximum length of the decoded body, in bytes.
+        max_len: usize,
+    },
+}
+
+impl<E: fmt::Display> fmt::Display for DecodingError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Http(err) => err.fmt(f),
+            Self::Decode(err) => write!(f, "Couldn't decode the response body: {err}"),
+            Self::TooLarge { max_len } => {
+                write!(f, "The decoded response body is longer than {max_len} bytes")
+            }
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for DecodingError<E> {}
+
+#[cfg(all(test, feature = "gzip", feature = "mock"))]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{write::GzEncoder, Compression};
+    use http::header::{ACCEPT_ENCODING, CONTENT_ENCODING};
+
+    use super::{DecodingError, DecodingHttpClient};
+    use crate::{http_client::MockHttpClient, HttpClient};
+
+    const JSON: &[u8] = br#"{"next_batch":"s1"}"#;
+
+    /// A `MockHttpClient` that returns the given body with the given `Content-Encoding`.
+    fn encoded_client(content_encoding: &'static str, body: Vec<u8>) -> MockHttpClient {
+        let client = MockHttpClient::new();
+        client.respond_with(
+            |req| req.headers()[ACCEPT_ENCODING].to_str().unwrap().contains("gzip"),
+            http::Response::builder()
+                .header(CONTENT_ENCODING, content_encoding)
+                .body(body)
+                .unwrap(),
+        );
+        client
+    }
+
+    /// A `MockHttpClient` that applies gzip `times` times to [`JSON`].
+    fn gzip_client(content_encoding: &'static str, times: usize) -> MockHttpClient {
+        let body = (0..times).fold(JSON.to_vec(), |body, _| {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&body).unwrap();
+            encoder.finish().unwrap()
+        });
+
+        encoded_client(content_encoding, body)
+    }
+
+    async fn send(client: MockHttpClient) -> http::Response<Vec<u8>> {
+        DecodingHttpClient::new(client)
+            .send_http_request(http::Request::new(Vec::new()))
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn decode_gzip_body() {
+        let res = send(gzip_client("gzip", 1)).await;
+
+        assert!(res.headers().get(CONTENT_ENCODING).is_none());
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(res.body()).unwrap(),
+            serde_json::json!({ "next_batch": "s1" })
+        );
+    }
+
+    #[tokio::test]
+    async fn decode_gzip_aliases() {
+        assert_eq!(send(gzip_client("GZip", 1)).await.body(), JSON);
+        assert_eq!(send(gzip_client("x-gzip", 1)).await.body(), JSON);
+    }
+
+    #[tokio::test]
+    async fn decode_stacked_encodings() {
+        let res = send(gzip_client("gzip, identity, x-gzip", 2)).await;
+        assert_eq!(res.body(), JSON);
+    }
+
+    #[tokio::test]
+    async fn keep_unsupported_encoding() {
+        let res = send(encoded_client("gzip, br", b"abc".to_vec())).await;
+
+        assert_eq!(res.headers()[CONTENT_ENCODING], "gzip, br");
+        assert_eq!(res.body(), b"abc");
+    }
+
+    #[tokio::test]
+    async fn decoded_body_too_large() {
+        let client =
+            DecodingHttpClient::with_max_decoded_len(gzip_client("gzip", 1), JSON.len() - 1);
+        let err = client.send_http_request(http::Request::new(Vec::new())).await.unwrap_err();
+
+        assert!(matches!(err, DecodingError::TooLarge { max_len } if max_len == JSON.len() - 1));
+    }
+}
@@ -4,6 +4,12 @@ use hyper::client::{connect::Connect, HttpConnector};
 
 use super::{DefaultConstructibleHttpClient, HttpClient};
 
+#[cfg(all(unix, feature = "hyper-unix"))]
+mod unix;
+
+#[cfg(all(unix, feature = "hyper-unix"))]
+pub use self::unix::{HyperUnix, UnixConnection, UnixConnector};
+
 /// A basic hyper HTTP client.
 ///
 /// You basically never want this, since it doesn't support `https`.
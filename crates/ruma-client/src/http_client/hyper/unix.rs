@@ -0,0 +1,165 @@
+use std::{
+    future::Future,
+    io,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use hyper::{
+    client::connect::{Connected, Connection},
+    service::Service,
+    Uri,
+};
+use tokio::{
+    io::{AsyncRead, AsyncWrite, ReadBuf},
+    net::UnixStream,
+};
+
+/// A hyper HTTP client connecting to the homeserver over a unix domain socket.
+///
+/// This client does not implement `DefaultConstructibleHttpClient`. To use it, you need to manually
+/// construct it with the path of the socket:
+///
+/// ```no_run
+/// use ruma_client::http_client::{HyperUnix, UnixConnector};
+///
+/// let client: HyperUnix = hyper::Client::builder().build(UnixConnector::new("/run/matrix.sock"));
+/// ```
+pub type HyperUnix = hyper::Client<UnixConnector>;
+
+/// A hyper connector that connects to a unix domain socket.
+///
+/// Every request is sent to the configured socket, regardless of the host of its URL. The URL of
+/// the requests must still be a valid `http` URL, like `http://localhost/_matrix/client/versions`.
+#[derive(Clone, Debug)]
+pub struct UnixConnector {
+    socket_path: Arc<Path>,
+}
+
+impl UnixConnector {
+    /// Creates a new `UnixConnector` connecting to the socket at the given path.
+    pub fn new(socket_path: impl Into<PathBuf>) -> Self {
+        Self { socket_path: socket_path.into().into() }
+    }
+
+    /// The path of the socket this connector connects to.
+    pub fn socket_path(&self) -> &Path {
+        &self.socket_path
+    }
+}
+
+impl Service<Uri> for UnixConnector {
+    type Response = UnixConnection;
+    type Error = io::Error;
+    type Future = Pin<Box<dyn Future<Output = io::Result<UnixConnection>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _uri: Uri) -> Self::Future {
+        let socket_path = self.socket_path.clone();
+        Box::pin(async move { UnixStream::connect(&*socket_path).await.map(UnixConnection) })
+    }
+}
+
+/// A connection to a unix domain socket, opened by a [`UnixConnector`].
+#[derive(Debug)]
+pub struct UnixConnection(UnixStream);
+
+impl AsyncRead for UnixConnection {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for UnixConnection {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.0).poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.0).poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.0.is_write_vectored()
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_shutdown(cx)
+    }
+}
+
+impl Connection for UnixConnection {
+    fn connected(&self) -> Connected {
+        Connected::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::UnixListener,
+    };
+
+    use super::{HyperUnix, UnixConnector};
+    use crate::HttpClient;
+
+    #[tokio::test]
+    async fn unix_socket_round_trip() {
+        let socket_path =
+            std::env::temp_dir().join(format!("ruma-client-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&socket_path);
+        let listener = UnixListener::bind(&socket_path).unwrap();
+
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let len = stream.read(&mut buf).await.unwrap();
+                assert_ne!(len, 0, "connection closed before the end of the request");
+                request.extend_from_slice(&buf[..len]);
+            }
+
+            stream.write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 2\r\n\r\n{}").await.unwrap();
+            request
+        });
+
+        let client: HyperUnix = hyper::Client::builder().build(UnixConnector::new(&socket_path));
+        let req = http::Request::get("http://localhost/_matrix/client/versions")
+            .body(BytesMut::new())
+            .unwrap();
+        let res = client.send_http_request(req).await.unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::OK);
+        assert_eq!(res.body().as_ref(), b"{}");
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with(b"GET /_matrix/client/versions HTTP/1.1\r\n"));
+
+        std::fs::remove_file(&socket_path).unwrap();
+    }
+}
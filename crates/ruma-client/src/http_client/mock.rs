@@ -0,0 +1,239 @@
+use std::{
+    fmt, future,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use async_trait::async_trait;
+
+use super::HttpClient;
+
+type Matcher = Box<dyn Fn(&http::Request<Vec<u8>>) -> bool + Send + Sync>;
+
+/// A registered response, or `None` if the matching requests never get a response.
+type MockResponse = (Matcher, Option<http::Response<Vec<u8>>>);
+
+/// An `HttpClient` that returns canned responses, for testing.
+///
+/// Responses are registered with a matcher, and the response of the first registered matcher that
+/// matches a request is returned. Every request that is sent through this client is recorded and
+/// can be inspected with [`MockHttpClient::requests`].
+///
+/// Clones of a `MockHttpClient` share the registered responses and the recorded requests, so a
+/// clone can be kept to inspect the requests after moving the client into a [`Client`].
+///
+/// [`Client`]: crate::Client
+///
+/// ```
+/// # async {
+/// use ruma_client::{http_client::MockHttpClient, HttpClient};
+///
+/// let client = MockHttpClient::new();
+/// client.respond_to_path("/_matrix/client/versions", http::Response::new(b"{}".to_vec()));
+///
+/// let req = http::Request::get("https://homeserver.tld/_matrix/client/versions")
+///     .body(Vec::new())
+///     .unwrap();
+/// let res = client.send_http_request(req).await.unwrap();
+/// assert_eq!(res.body(), b"{}");
+/// assert_eq!(client.requests().len(), 1);
+/// # };
+/// ```
+#[derive(Clone, Default)]
+pub struct MockHttpClient {
+    responses: Arc<Mutex<Vec<MockResponse>>>,
+    requests: Arc<Mutex<Vec<http::Request<Vec<u8>>>>>,
+}
+
+impl MockHttpClient {
+    /// Creates a new `MockHttpClient` without any registered response.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a response to return for the requests matching the given function.
+    pub fn respond_with<F>(&self, matcher: F, response: http::Response<Vec<u8>>)
+    where
+        F: Fn(&http::Request<Vec<u8>>) -> bool + Send + Sync + 'static,
+    {
+        lock(&self.responses).push((Box::new(matcher), Some(response)));
+    }
+
+    /// Register a response to return for the requests to the given URL path.
+    pub fn respond_to_path(&self, path: &str, response: http::Response<Vec<u8>>) {
+        let path = path.to_owned();
+        self.respond_with(move |req| req.uri().path() == path, response);
+    }
+
+    /// Never respond to the requests matching the given function.
+    ///
+    /// This is useful to test timeouts.
+    pub fn never_respond_to<F>(&self, matcher: F)
+    where
+        F: Fn(&http::Request<Vec<u8>>) -> bool + Send + Sync + 'static,
+    {
+        lock(&self.responses).push((Box::new(matcher), None));
+    }
+
+    /// Get the requests received by this client, in the order they were sent.
+    pub fn requests(&self) -> MutexGuard<'_, Vec<http::Request<Vec<u8>>>> {
+        lock(&self.requests)
+    }
+}
+
+impl fmt::Debug for MockHttpClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockHttpClient")
+            .field("responses", &lock(&self.responses).len())
+            .field("requests", &lock(&self.requests).len())
+            .finish()
+    }
+}
+
+#[async_trait]
+impl HttpClient for MockHttpClient {
+    type RequestBody = Vec<u8>;
+    type ResponseBody = Vec<u8>;
+    type Error = UnmatchedRequest;
+
+    async fn send_http_request(
+        &self,
+        req: http::Request<Self::RequestBody>,
+    ) -> Result<http::Response<Self::ResponseBody>, Self::Error> {
+        let res = lock(&self.responses)
+            .iter()
+            .find(|(matcher, _)| matcher(&req))
+            .map(|(_, res)| res.as_ref().map(clone_response))
+            .ok_or_else(|| UnmatchedRequest {
+                method: req.method().clone(),
+                uri: req.uri().clone(),
+            });
+
+        lock(&self.requests).push(req);
+
+        match res {
+            Ok(Some(res)) => Ok(res),
+            Ok(None) => future::pending().await,
+            Err(err) => Err(err),
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // A panic while holding the lock doesn't leave the data in an inconsistent state.
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn clone_response(res: &http::Response<Vec<u8>>) -> http::Response<Vec<u8>> {
+    let mut new_res = http::Response::new(res.body().clone());
+    *new_res.status_mut() = res.status();
+    *new_res.version_mut() = res.version();
+    *new_res.headers_mut() = res.headers().clone();
+
+    new_res
+}
+
+/// The error returned by [`MockHttpClient`] when no registered response matches a request.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct UnmatchedRequest {
+    /// The method of the request.
+    pub method: http::Method,
+
+    /// The URI of the request.
+    pub uri: http::Uri,
+}
+
+impl fmt::Display for UnmatchedRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "No response matches the request {} {}", self.method, self.uri)
+    }
+}
+
+impl std::error::Error for UnmatchedRequest {}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use http::{Method, StatusCode};
+    use tokio::time::timeout;
+
+    use super::MockHttpClient;
+    use crate::HttpClient;
+
+    fn request(method: Method, path: &str) -> http::Request<Vec<u8>> {
+        http::Request::builder()
+            .method(method)
+            .uri(format!("https://homeserver.tld{path}"))
+            .body(b"{}".to_vec())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn canned_responses() {
+        let client = MockHttpClient::new();
+        client.respond_with(
+            |req| req.method() == Method::POST,
+            http::Response::builder().status(StatusCode::FORBIDDEN).body(Vec::new()).unwrap(),
+        );
+        client.respond_to_path("/foo", http::Response::new(b"foo".to_vec()));
+        client.respond_to_path("/bar", http::Response::new(b"bar".to_vec()));
+
+        let res = client.send_http_request(request(Method::GET, "/bar")).await.unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.body(), b"bar");
+
+        // Responses can be returned several times.
+        let res = client.send_http_request(request(Method::GET, "/bar")).await.unwrap();
+        assert_eq!(res.body(), b"bar");
+
+        // The first matching response is returned.
+        let res = client.send_http_request(request(Method::POST, "/foo")).await.unwrap();
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+
+        let err = client.send_http_request(request(Method::GET, "/baz")).await.unwrap_err();
+        assert_eq!(err.method, Method::GET);
+        assert_eq!(err.uri, "https://homeserver.tld/baz");
+    }
+
+    #[tokio::test]
+    async fn request_capture() {
+        let client = MockHttpClient::new();
+        client.respond_to_path("/foo", http::Response::new(Vec::new()));
+
+        client.send_http_request(request(Method::GET, "/foo")).await.unwrap();
+        client.send_http_request(request(Method::PUT, "/bar")).await.unwrap_err();
+
+        let requests = client.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method(), Method::GET);
+        assert_eq!(requests[0].uri().path(), "/foo");
+        assert_eq!(requests[1].method(), Method::PUT);
+        assert_eq!(requests[1].uri().path(), "/bar");
+        assert_eq!(requests[1].body(), b"{}");
+    }
+
+    #[tokio::test]
+    async fn shared_between_clones() {
+        let client = MockHttpClient::new();
+        let clone = client.clone();
+        clone.respond_to_path("/foo", http::Response::new(Vec::new()));
+
+        client.send_http_request(request(Method::GET, "/foo")).await.unwrap();
+        assert_eq!(clone.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn no_response() {
+        let client = MockHttpClient::new();
+        client.never_respond_to(|req| req.uri().path() == "/foo");
+
+        let res = timeout(
+            Duration::from_millis(10),
+            client.send_http_request(request(Method::GET, "/foo")),
+        )
+        .await;
+        assert!(res.is_err());
+        assert_eq!(client.requests().len(), 1);
+    }
+}
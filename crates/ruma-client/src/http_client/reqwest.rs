@@ -6,6 +6,21 @@ use bytes::{Bytes, BytesMut};
 use super::{DefaultConstructibleHttpClient, HttpClient};
 
 /// The `reqwest` crate's `Client`.
+///
+/// Since this is the `reqwest::Client` itself, a client configured with `reqwest::ClientBuilder`,
+/// e.g. to use a proxy, timeouts or custom connection pooling settings, can be used anywhere an
+/// [`HttpClient`] is expected, like in `ClientBuilder::http_client`:
+///
+/// ```
+/// use std::time::Duration;
+///
+/// let http_client = reqwest::Client::builder()
+///     .proxy(reqwest::Proxy::all("http://proxy.example.org:8080")?)
+///     .timeout(Duration::from_secs(30))
+///     .pool_idle_timeout(Duration::from_secs(60))
+///     .build()?;
+/// # Ok::<(), reqwest::Error>(())
+/// ```
 pub type Reqwest = reqwest::Client;
 
 #[async_trait]
@@ -37,3 +52,23 @@ impl DefaultConstructibleHttpClient for Reqwest {
         reqwest::Client::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Reqwest;
+    use crate::HttpClient;
+
+    fn assert_http_client<C: HttpClient>(_: &C) {}
+
+    #[test]
+    fn custom_client() {
+        let client: Reqwest = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .pool_max_idle_per_host(2)
+            .build()
+            .unwrap();
+        assert_http_client(&client);
+    }
+}
@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::HttpClient;
+
+/// An `HttpClient` that retries requests that were rate-limited by the homeserver.
+///
+/// When the inner client gets back a response with the `429 Too Many Requests` status code, this
+/// client waits for the delay suggested by the homeserver in the `retry_after_ms` field of the
+/// error, or [`RetryHttpClient::DEFAULT_RETRY_DELAY`] if there is none, and sends the request
+/// again. After `max_retries` retries, the rate-limited response is returned as-is.
+///
+/// This uses the timer of the tokio runtime, so it is only available with the `hyper` or `reqwest`
+/// features.
+#[derive(Clone, Debug)]
+pub struct RetryHttpClient<C> {
+    inner: C,
+    max_retries: u32,
+}
+
+impl<C> RetryHttpClient<C> {
+    /// The delay to wait for before retrying a request, if the homeserver doesn't suggest one.
+    pub const DEFAULT_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+    /// Creates a new `RetryHttpClient` wrapping the given client, that retries a rate-limited
+    /// request at most `max_retries` times.
+    pub fn new(inner: C, max_retries: u32) -> Self {
+        Self { inner, max_retries }
+    }
+
+    /// Get a reference to the inner client.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Get the inner client.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+#[async_trait]
+impl<C> HttpClient for RetryHttpClient<C>
+where
+    C: HttpClient + Send,
+    C::RequestBody: Clone + Sync,
+    C::ResponseBody: Send,
+{
+    type RequestBody = C::RequestBody;
+    type ResponseBody = C::ResponseBody;
+    type Error = C::Error;
+
+    async fn send_http_request(
+        &self,
+        req: http::Request<Self::RequestBody>,
+    ) -> Result<http::Response<Self::ResponseBody>, Self::Error> {
+        let mut retries = 0;
+
+        loop {
+            let res = self.inner.send_http_request(clone_request(&req)).await?;
+
+            if res.status() != http::StatusCode::TOO_MANY_REQUESTS || retries >= self.max_retries {
+                return Ok(res);
+            }
+
+            let delay = retry_after(res.body().as_ref()).unwrap_or(Self::DEFAULT_RETRY_DELAY);
+            drop(res);
+
+            tokio::time::sleep(delay).await;
+            retries += 1;
+        }
+    }
+}
+
+fn clone_request<B: Clone>(req: &http::Request<B>) -> http::Request<B> {
+    let mut new_req = http::Request::new(req.body().clone());
+    *new_req.method_mut() = req.method().clone();
+    *new_req.uri_mut() = req.uri().clone();
+    *new_req.version_mut() = req.version();
+    *new_req.headers_mut() = req.headers().clone();
+
+    new_req
+}
+
+/// Get the delay suggested by the homeserver in the body of a `M_LIMIT_EXCEEDED` error.
+fn retry_after(body: &[u8]) -> Option<Duration> {
+    #[derive(Deserialize)]
+    struct LimitExceededBody {
+        retry_after_ms: u64,
+    }
+
+    serde_json::from_slice::<LimitExceededBody>(body)
+        .ok()
+        .map(|body| Duration::from_millis(body.retry_after_ms))
+}
@@ -87,6 +87,7 @@
 //! * `hyper`
 //! * `hyper-native-tls`
 //! * `hyper-rustls`
+//! * `hyper-unix` – connect to the homeserver over a unix domain socket, only available on unix
 //! * `isahc`
 //! * `reqwest` – if you use the `reqwest` library already, activate this feature and configure the
 //!   TLS backend on `reqwest` directly. If you want to use `reqwest` but don't depend on it
@@ -98,6 +99,18 @@
 //!   * `reqwest-rustls-manual-roots`
 //!   * `reqwest-rustls-webpki-roots`
 //!   * `reqwest-rustls-native-roots`
+//!
+//! The following features activate the decoding of compressed responses by
+//! [`http_client::DecodingHttpClient`]:
+//!
+//! * `gzip`
+//! * `deflate`
+//!
+//! The `hyper` and `reqwest` features also activate [`http_client::RetryHttpClient`] and
+//! [`HttpClientExt::send_matrix_request_with_timeout`], that use the timer of the tokio runtime.
+//!
+//! The `mock` feature activates [`http_client::MockHttpClient`], an http client returning canned
+//! responses, for testing.
 
 #![warn(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
@@ -105,7 +118,7 @@
 use std::{any::type_name, future::Future};
 
 use ruma_common::{
-    api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+    api::{AuthScheme, MatrixVersion, OutgoingRequest, SendAccessToken},
     UserId,
 };
 use tracing::{info_span, Instrument};
@@ -130,6 +143,17 @@ pub type ResponseError<C, R> =
 pub type ResponseResult<C, R> =
     Result<<R as OutgoingRequest>::IncomingResponse, ResponseError<C, R>>;
 
+/// Get the `SendAccessToken` to use with the given access token for the request `R`.
+///
+/// The access token is only sent if the endpoint of `R` requires authentication with an access
+/// token, so it is never sent to an endpoint that doesn't expect one.
+pub fn access_token_for<R: OutgoingRequest>(access_token: Option<&str>) -> SendAccessToken<'_> {
+    match (R::METADATA.authentication, access_token) {
+        (AuthScheme::AccessToken, Some(token)) => SendAccessToken::Always(token),
+        _ => SendAccessToken::None,
+    }
+}
+
 fn send_customized_request<'a, C, R, F>(
     http_client: &'a C,
     homeserver_url: &str,
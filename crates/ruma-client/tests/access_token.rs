@@ -0,0 +1,18 @@
+use ruma_client::access_token_for;
+use ruma_client_api::{discovery::get_supported_versions, room::aliases};
+use ruma_common::api::SendAccessToken;
+
+#[test]
+fn access_token_for_authenticated_endpoint() {
+    let token = access_token_for::<aliases::v3::Request>(Some("tok"));
+    assert!(matches!(token, SendAccessToken::Always("tok")));
+
+    let token = access_token_for::<aliases::v3::Request>(None);
+    assert!(matches!(token, SendAccessToken::None));
+}
+
+#[test]
+fn access_token_for_unauthenticated_endpoint() {
+    let token = access_token_for::<get_supported_versions::Request>(Some("tok"));
+    assert!(matches!(token, SendAccessToken::None));
+}
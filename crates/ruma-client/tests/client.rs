@@ -0,0 +1,57 @@
+#![cfg(all(feature = "client-api", feature = "mock"))]
+
+use ruma_client::{http_client::MockHttpClient, Client, Error};
+use ruma_common::{api::MatrixVersion, presence::PresenceState};
+use tokio_stream::StreamExt as _;
+
+/// A `MockHttpClient` that answers `/sync` requests with two batches, then with errors.
+fn sync_client() -> MockHttpClient {
+    let client = MockHttpClient::new();
+    let since = |since: &'static str| {
+        move |req: &http::Request<Vec<u8>>| {
+            req.uri().query().unwrap_or_default().contains(&format!("since={since}"))
+        }
+    };
+
+    client.respond_with(since("b0"), http::Response::new(br#"{ "next_batch": "b1" }"#.to_vec()));
+    client.respond_with(since("b1"), http::Response::new(br#"{ "next_batch": "b2" }"#.to_vec()));
+    client.respond_with(
+        |_| true,
+        http::Response::builder()
+            .status(http::StatusCode::INTERNAL_SERVER_ERROR)
+            .body(br#"{ "errcode": "M_UNKNOWN", "error": "oops" }"#.to_vec())
+            .unwrap(),
+    );
+
+    client
+}
+
+#[tokio::test]
+async fn sync_stream_yields_errors() {
+    let http_client = sync_client();
+    let client = Client::builder()
+        .homeserver_url("https://homeserver.tld".to_owned())
+        .access_token(Some("tok".to_owned()))
+        .supported_matrix_versions(vec![MatrixVersion::V1_1])
+        .http_client(http_client.clone())
+        .await
+        .unwrap();
+
+    let mut sync_stream =
+        Box::pin(client.sync_stream(None, "b0".to_owned(), PresenceState::Online, None));
+
+    assert_eq!(sync_stream.next().await.unwrap().unwrap().next_batch, "b1");
+    assert_eq!(sync_stream.next().await.unwrap().unwrap().next_batch, "b2");
+    assert!(matches!(sync_stream.next().await.unwrap(), Err(Error::FromHttpResponse(_))));
+    assert!(matches!(sync_stream.next().await.unwrap(), Err(Error::FromHttpResponse(_))));
+
+    let queries: Vec<_> = http_client
+        .requests()
+        .iter()
+        .map(|req| req.uri().query().unwrap_or_default().to_owned())
+        .collect();
+    assert!(queries[0].contains("since=b0"));
+    assert!(queries[1].contains("since=b1"));
+    assert!(queries[2].contains("since=b2"));
+    assert!(queries[3].contains("since=b2"));
+}
@@ -0,0 +1,181 @@
+#![cfg(feature = "mock")]
+
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+use ruma_client::{http_client::MockHttpClient, Error, HttpClientExt};
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+use ruma_client::{http_client::RetryHttpClient, HttpClient};
+use ruma_client_api::room::aliases;
+use ruma_common::{
+    api::{error::FromHttpResponseError, MatrixVersion, SendAccessToken},
+    room_id,
+};
+
+fn aliases_response() -> http::Response<Vec<u8>> {
+    http::Response::new(br#"{ "aliases": [] }"#.to_vec())
+}
+
+/// A `MockHttpClient` that answers all the requests with the same response.
+fn aliases_client() -> MockHttpClient {
+    let client = MockHttpClient::new();
+    client.respond_with(|_| true, aliases_response());
+    client
+}
+
+#[tokio::test]
+async fn send_matrix_request_for_versions() {
+    let client = aliases_client();
+    let request = || aliases::v3::Request::new(room_id!("!room:localhost").to_owned());
+
+    for versions in [&[MatrixVersion::V1_0][..], &[MatrixVersion::V1_0, MatrixVersion::V1_1], &[]] {
+        client
+            .send_matrix_request(
+                "https://homeserver.tld",
+                SendAccessToken::Always("tok"),
+                versions,
+                request(),
+            )
+            .await
+            .unwrap();
+    }
+
+    let paths: Vec<_> = client.requests().iter().map(|req| req.uri().path().to_owned()).collect();
+    assert_eq!(
+        paths,
+        [
+            "/_matrix/client/r0/rooms/!room:localhost/aliases",
+            "/_matrix/client/v3/rooms/!room:localhost/aliases",
+            "/_matrix/client/unstable/org.matrix.msc2432/rooms/!room:localhost/aliases",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn send_matrix_request_with_headers() {
+    let client = aliases_client();
+    let mut headers = http::HeaderMap::new();
+    headers.insert(http::header::USER_AGENT, http::HeaderValue::from_static("ruma-test"));
+
+    client
+        .send_matrix_request_with_headers(
+            "https://homeserver.tld",
+            SendAccessToken::Always("tok"),
+            &[MatrixVersion::V1_1],
+            aliases::v3::Request::new(room_id!("!room:localhost").to_owned()),
+            headers,
+        )
+        .await
+        .unwrap();
+
+    let requests = client.requests();
+    assert_eq!(requests[0].headers()[http::header::USER_AGENT], "ruma-test");
+    assert_eq!(requests[0].headers()[http::header::AUTHORIZATION], "Bearer tok");
+}
+
+/// A `MockHttpClient` that answers the first requests with a `429 Too Many Requests` response.
+fn rate_limited_client(rate_limited_responses: u32) -> MockHttpClient {
+    let client = MockHttpClient::new();
+    let attempts = AtomicU32::new(0);
+
+    client.respond_with(
+        move |_| attempts.fetch_add(1, Ordering::SeqCst) < rate_limited_responses,
+        http::Response::builder()
+            .status(http::StatusCode::TOO_MANY_REQUESTS)
+            .body(
+                br#"{ "errcode": "M_LIMIT_EXCEEDED", "error": "Too many requests", "retry_after_ms": 10 }"#
+                    .to_vec(),
+            )
+            .unwrap(),
+    );
+    client.respond_with(|_| true, aliases_response());
+
+    client
+}
+
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+#[tokio::test]
+async fn retry_rate_limited_request() {
+    let client = RetryHttpClient::new(rate_limited_client(2), 3);
+
+    client
+        .send_matrix_request(
+            "https://homeserver.tld",
+            SendAccessToken::Always("tok"),
+            &[MatrixVersion::V1_1],
+            aliases::v3::Request::new(room_id!("!room:localhost").to_owned()),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(client.inner().requests().len(), 3);
+}
+
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+#[tokio::test]
+async fn retry_rate_limited_request_max_retries() {
+    let client = RetryHttpClient::new(rate_limited_client(5), 2);
+
+    let res = client.send_http_request(http::Request::new(Vec::new())).await.unwrap();
+
+    assert_eq!(res.status(), http::StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(client.inner().requests().len(), 3);
+}
+
+#[tokio::test]
+async fn rate_limited_request_retry_after() {
+    let client = rate_limited_client(1);
+
+    let res = client
+        .send_matrix_request(
+            "https://homeserver.tld",
+            SendAccessToken::Always("tok"),
+            &[MatrixVersion::V1_1],
+            aliases::v3::Request::new(room_id!("!room:localhost").to_owned()),
+        )
+        .await;
+
+    match res {
+        Err(Error::FromHttpResponse(FromHttpResponseError::Server(error))) => {
+            assert_eq!(error.status_code, http::StatusCode::TOO_MANY_REQUESTS);
+            assert_eq!(error.retry_after(), Some(Duration::from_millis(10)));
+        }
+        res => panic!("expected a rate-limited error, got {res:?}"),
+    }
+}
+
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+#[tokio::test]
+async fn send_matrix_request_with_timeout() {
+    let client = MockHttpClient::new();
+    client.never_respond_to(|_| true);
+
+    let res = client
+        .send_matrix_request_with_timeout(
+            "https://homeserver.tld",
+            SendAccessToken::Always("tok"),
+            &[MatrixVersion::V1_1],
+            aliases::v3::Request::new(room_id!("!room:localhost").to_owned()),
+            Duration::from_millis(10),
+        )
+        .await;
+
+    assert!(matches!(res, Err(Error::Timeout)));
+}
+
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+#[tokio::test]
+async fn send_matrix_request_before_timeout() {
+    aliases_client()
+        .send_matrix_request_with_timeout(
+            "https://homeserver.tld",
+            SendAccessToken::Always("tok"),
+            &[MatrixVersion::V1_1],
+            aliases::v3::Request::new(room_id!("!room:localhost").to_owned()),
+            Duration::from_secs(10),
+        )
+        .await
+        .unwrap();
+}
@@ -44,8 +44,13 @@ use crate::UserId;
 /// * `#[ruma_api(path)]`: Fields with this attribute will be inserted into the matching path
 ///   component of the request URL. If there are multiple of these fields, the order in which
 ///   they are declared must match the order in which they occur in the request path.
+///   `#[ruma_api(path, validate = "fn_name")]` additionally calls `fn_name(&field)` after the
+///   field is deserialized in `try_from_http_request`. The function must return a
+///   `Result<(), E>` where `E` converts into [`DeserializationError`][error::DeserializationError].
 /// * `#[ruma_api(query)]`: Fields with this attribute will be inserting into the URL's query
-///   string.
+///   string. Lists are serialized by repeating the key for every value by default
+///   (`key=val1&key=val2`). Use `#[ruma_api(query, style = "comma")]` to join the values with
+///   commas instead (`key=val1,val2`).
 /// * `#[ruma_api(query_map)]`: Instead of individual query fields, one query_map field, of any
 ///   type that implements `IntoIterator<Item = (String, String)>` (e.g. `HashMap<String,
 ///   String>`, can be used for cases where an endpoint supports arbitrary query parameters.
@@ -59,6 +64,14 @@ use crate::UserId;
 ///   entire request body, but this attribute is for endpoints where the body can be anything,
 ///   not just JSON. The field type must be `Vec<u8>`.
 ///
+/// The attribute itself accepts the following arguments:
+///
+/// * `error = ErrorType`: The type of the errors returned by the endpoint. Defaults to
+///   [`MatrixError`][error::MatrixError].
+/// * `max_body = 65536`: The maximum size of the serialized request body, in bytes.
+///   `try_into_http_request` returns [`IntoHttpError::BodyTooLarge`][error::IntoHttpError] if
+///   the body is larger.
+///
 /// ## Examples
 ///
 /// ```
@@ -160,6 +173,16 @@ pub use ruma_macros::request;
 ///   entire response body, but this attribute is for endpoints where the body can be anything,
 ///   not just JSON. The field type must be `Vec<u8>`.
 ///
+/// The attribute itself accepts the following arguments:
+///
+/// * `error = ErrorType`: The type of the errors returned by the endpoint. Defaults to
+///   [`MatrixError`][error::MatrixError].
+/// * `check_content_type`: Check that the `Content-Type` of a response with a JSON body is
+///   `application/json`, if it is set. `try_from_http_response` returns
+///   [`HeaderDeserializationError::UnexpectedContentType`][error::HeaderDeserializationError]
+///   otherwise. This should not be used for endpoints that are commonly served with another
+///   `Content-Type`, like the `.well-known` endpoints.
+///
 /// ## Examples
 ///
 /// ```
@@ -308,6 +331,18 @@ pub trait OutgoingRequest: Sized + Clone {
         access_token: SendAccessToken<'_>,
         considering_versions: &'_ [MatrixVersion],
     ) -> Result<http::Request<T>, IntoHttpError>;
+
+    /// Get the length of the body of the `http::Request` generated for this request, if it is
+    /// known without serializing the request.
+    ///
+    /// This can be used to check the size of a request against the limits of the server before
+    /// sending it, like the maximum upload size of the content repository.
+    ///
+    /// The default implementation returns `None`. Requests generated with the [`request`] macro
+    /// implement it when they have a `raw_body` field.
+    fn estimated_body_len(&self) -> Option<usize> {
+        None
+    }
 }
 
 /// A response type for a Matrix API endpoint, used for receiving responses.
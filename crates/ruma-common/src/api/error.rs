@@ -132,6 +132,16 @@ pub enum IntoHttpError {
     /// HTTP request construction failed.
     #[error("HTTP request construction failed: {0}")]
     Http(#[from] http::Error),
+
+    /// The serialized request body is larger than the maximum size allowed for this endpoint.
+    #[error("request body is {len} bytes long, but the maximum size is {max} bytes")]
+    BodyTooLarge {
+        /// The length of the serialized request body, in bytes.
+        len: usize,
+
+        /// The maximum length of the request body for this endpoint, in bytes.
+        max: usize,
+    },
 }
 
 /// An error when converting a http request to one of ruma's endpoint-specific request types.
@@ -262,6 +272,35 @@ pub enum HeaderDeserializationError {
     /// The given required header is missing.
     #[error("missing header `{0}`")]
     MissingHeader(String),
+
+    /// The `Content-Type` header of a response with a JSON body is not `application/json`.
+    ///
+    /// This usually happens when the response comes from something else than the homeserver, like
+    /// an HTML error page from a misconfigured reverse proxy.
+    #[error("unexpected content type `{0}`, expected `application/json`")]
+    UnexpectedContentType(String),
+}
+
+/// Check that the `Content-Type` header, if present, is `application/json`.
+///
+/// Used by the generated `IncomingResponse` implementations for responses with a JSON body, when
+/// `#[response(check_content_type)]` is set.
+#[doc(hidden)]
+pub fn _check_json_content_type(
+    headers: &http::HeaderMap,
+) -> Result<(), HeaderDeserializationError> {
+    let content_type = match headers.get(http::header::CONTENT_TYPE) {
+        Some(content_type) => content_type,
+        None => return Ok(()),
+    };
+
+    let content_type = content_type.to_str().map_err(HeaderDeserializationError::ToStrError)?;
+    let media_type = content_type.split(';').next().unwrap_or_default().trim();
+    if !media_type.eq_ignore_ascii_case("application/json") {
+        return Err(HeaderDeserializationError::UnexpectedContentType(content_type.to_owned()));
+    }
+
+    Ok(())
 }
 
 /// An error that happens when Ruma cannot understand a Matrix version.
@@ -669,6 +669,18 @@ impl MatrixVersion {
     }
 }
 
+impl PartialOrd for MatrixVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MatrixVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.const_ord(other)
+    }
+}
+
 impl Display for MatrixVersion {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let (major, minor) = self.into_parts();
@@ -683,7 +695,7 @@ mod tests {
 
     use super::{
         AuthScheme,
-        MatrixVersion::{self, V1_0, V1_1, V1_2, V1_3},
+        MatrixVersion::{self, V1_0, V1_1, V1_2, V1_3, V1_5},
         Metadata, VersionHistory,
     };
     use crate::api::error::IntoHttpError;
@@ -802,4 +814,22 @@ mod tests {
 
         assert_eq!(LIT, V1_0);
     }
+
+    #[test]
+    fn parse_and_order_versions() {
+        let r0_6_1: MatrixVersion = "r0.6.1".parse().unwrap();
+        let v1_1: MatrixVersion = "v1.1".parse().unwrap();
+        let v1_5: MatrixVersion = "v1.5".parse().unwrap();
+        assert_eq!(r0_6_1, V1_0);
+        assert_eq!(v1_1, V1_1);
+        assert_eq!(v1_5, V1_5);
+
+        assert!(r0_6_1 < v1_1);
+        assert!(v1_1 < v1_5);
+        assert_eq!([v1_5, r0_6_1, v1_1].iter().max(), Some(&V1_5));
+
+        assert_eq!(v1_5.to_string(), "v1.5");
+        "v0.9".parse::<MatrixVersion>().unwrap_err();
+        "1.5".parse::<MatrixVersion>().unwrap_err();
+    }
 }
@@ -5,14 +5,15 @@ use std::{fmt, mem};
 use serde::Serialize;
 use serde_json::Value as JsonValue;
 
+mod ser;
 mod value;
 
-use crate::RoomVersionId;
 #[cfg(feature = "events")]
 use crate::{
     events::room::redaction::{OriginalRoomRedactionEvent, OriginalSyncRoomRedactionEvent},
     serde::Raw,
 };
+use crate::{serde::JsonObject, RoomVersionId};
 
 pub use self::value::{CanonicalJsonObject, CanonicalJsonValue};
 
@@ -24,6 +25,9 @@ pub enum CanonicalJsonError {
     /// The numeric value failed conversion to js_int::Int.
     IntConvert,
 
+    /// A floating-point number was found, which is not allowed in canonical JSON.
+    FloatNumber,
+
     /// An error occurred while serializing/deserializing.
     SerDe(serde_json::Error),
 }
@@ -34,6 +38,9 @@ impl fmt::Display for CanonicalJsonError {
             CanonicalJsonError::IntConvert => {
                 f.write_str("number found is not a valid `js_int::Int`")
             }
+            CanonicalJsonError::FloatNumber => {
+                f.write_str("floating-point numbers are not allowed in canonical JSON")
+            }
             CanonicalJsonError::SerDe(err) => write!(f, "serde Error: {err}"),
         }
     }
@@ -121,6 +128,23 @@ pub fn to_canonical_value<T: Serialize>(
     serde_json::to_value(value).map_err(CanonicalJsonError::SerDe)?.try_into()
 }
 
+/// Serialize the given value as a [canonical JSON] string.
+///
+/// This is the form of JSON used for signing and hashing events: object keys are sorted, there is
+/// no insignificant whitespace and only integers in the range of `js_int::Int` are allowed.
+///
+/// Unlike [`to_canonical_value`], the value is not converted to a `serde_json::Value` first, so
+/// floating-point numbers, including NaN and infinite values, are always rejected with
+/// [`CanonicalJsonError::FloatNumber`]. Integers outside of the range of `js_int::Int`, including
+/// integer map keys, are rejected with [`CanonicalJsonError::IntConvert`].
+///
+/// [canonical JSON]: https://spec.matrix.org/latest/appendices/#canonical-json
+pub fn to_canonical_json_string<T: Serialize + ?Sized>(
+    value: &T,
+) -> Result<String, CanonicalJsonError> {
+    value.serialize(ser::Serializer).map(|value| value.to_string())
+}
+
 /// The value to put in `unsigned.redacted_because`.
 ///
 /// See `From` implementations for ways to create an instance of this type.
@@ -196,7 +220,7 @@ pub fn redact_in_place(
 ) -> Result<(), RedactionError> {
     // Get the content keys here even if they're only needed inside the branch below, because we
     // can't teach rust that this is a disjoint borrow with `get_mut("content")`.
-    let allowed_content_keys: &[&str] = match event.get("type") {
+    let allowed_content_keys = match event.get("type") {
         Some(CanonicalJsonValue::String(event_type)) => {
             allowed_content_keys_for(event_type, version)
         }
@@ -210,12 +234,12 @@ pub fn redact_in_place(
             _ => return Err(RedactionError::not_of_type("content", JsonType::Object)),
         };
 
-        object_retain_keys(content, allowed_content_keys);
+        object_retain_keys(content, &allowed_content_keys);
     }
 
     let mut old_event = mem::take(event);
 
-    for &key in ALLOWED_KEYS {
+    for &key in allowed_keys_for(version) {
         if let Some(value) = old_event.remove(key) {
             event.insert(key.to_owned(), value);
         }
@@ -240,10 +264,30 @@ pub fn redact_content_in_place(
     version: &RoomVersionId,
     event_type: impl AsRef<str>,
 ) {
-    object_retain_keys(object, allowed_content_keys_for(event_type.as_ref(), version));
+    object_retain_keys(object, &allowed_content_keys_for(event_type.as_ref(), version));
+}
+
+/// Redacts event content using the rules specified in the Matrix client-server specification.
+///
+/// Like [`redact_content_in_place`], but operates on a plain JSON object, for content that hasn't
+/// been converted to canonical JSON.
+///
+/// Edits the `object` in-place.
+pub fn redact_json_content_in_place(
+    object: &mut JsonObject,
+    version: &RoomVersionId,
+    event_type: impl AsRef<str>,
+) {
+    json_object_retain_keys(object, &allowed_content_keys_for(event_type.as_ref(), version));
 }
 
-fn object_retain_keys(object: &mut CanonicalJsonObject, keys: &[&str]) {
+fn object_retain_keys(object: &mut CanonicalJsonObject, allowed: &AllowedKeys) {
+    let (keys, nested) = match *allowed {
+        AllowedKeys::All => return,
+        AllowedKeys::Some(keys) => (keys, &[][..]),
+        AllowedKeys::SomeNested(keys, nested) => (keys, nested),
+    };
+
     let mut old_content = mem::take(object);
 
     for &key in keys {
@@ -251,66 +295,153 @@ fn object_retain_keys(object: &mut CanonicalJsonObject, keys: &[&str]) {
             object.insert(key.to_owned(), value);
         }
     }
+
+    for &(key, nested_keys) in nested {
+        if let Some(CanonicalJsonValue::Object(mut nested_object)) = old_content.remove(key) {
+            object_retain_keys(&mut nested_object, &AllowedKeys::Some(nested_keys));
+
+            if !nested_object.is_empty() {
+                object.insert(key.to_owned(), nested_object.into());
+            }
+        }
+    }
+}
+
+fn json_object_retain_keys(object: &mut JsonObject, allowed: &AllowedKeys) {
+    let (keys, nested) = match *allowed {
+        AllowedKeys::All => return,
+        AllowedKeys::Some(keys) => (keys, &[][..]),
+        AllowedKeys::SomeNested(keys, nested) => (keys, nested),
+    };
+
+    object.retain(|key, value| {
+        if keys.contains(&key.as_str()) {
+            return true;
+        }
+
+        let nested_keys = nested.iter().find(|(nested_key, _)| nested_key == key);
+        match (nested_keys, value) {
+            (Some(&(_, nested_keys)), JsonValue::Object(nested_object)) => {
+                nested_object.retain(|key, _| nested_keys.contains(&key.as_str()));
+                !nested_object.is_empty()
+            }
+            _ => false,
+        }
+    });
+}
+
+/// The keys of the content of an event that are allowed to remain during redaction.
+enum AllowedKeys {
+    /// All keys are allowed.
+    All,
+
+    /// Only the given keys are allowed.
+    Some(&'static [&'static str]),
+
+    /// Only the given keys are allowed, and the given keys of the objects at the given nested
+    /// keys, if any.
+    SomeNested(&'static [&'static str], &'static [(&'static str, &'static [&'static str])]),
 }
 
 /// The fields that are allowed to remain in an event during redaction.
-static ALLOWED_KEYS: &[&str] = &[
-    "event_id",
-    "type",
-    "room_id",
-    "sender",
-    "state_key",
-    "content",
-    "hashes",
-    "signatures",
-    "depth",
-    "prev_events",
-    "prev_state",
-    "auth_events",
-    "origin",
-    "origin_server_ts",
-    "membership",
-];
-
-fn allowed_content_keys_for(event_type: &str, version: &RoomVersionId) -> &'static [&'static str] {
+fn allowed_keys_for(version: &RoomVersionId) -> &'static [&'static str] {
+    match version {
+        RoomVersionId::V11 => &[
+            "event_id",
+            "type",
+            "room_id",
+            "sender",
+            "state_key",
+            "content",
+            "hashes",
+            "signatures",
+            "depth",
+            "prev_events",
+            "auth_events",
+            "origin_server_ts",
+        ],
+        _ => &[
+            "event_id",
+            "type",
+            "room_id",
+            "sender",
+            "state_key",
+            "content",
+            "hashes",
+            "signatures",
+            "depth",
+            "prev_events",
+            "prev_state",
+            "auth_events",
+            "origin",
+            "origin_server_ts",
+            "membership",
+        ],
+    }
+}
+
+fn allowed_content_keys_for(event_type: &str, version: &RoomVersionId) -> AllowedKeys {
     match event_type {
         "m.room.member" => match version {
             RoomVersionId::V9 | RoomVersionId::V10 => {
-                &["membership", "join_authorised_via_users_server"]
+                AllowedKeys::Some(&["membership", "join_authorised_via_users_server"])
             }
-            _ => &["membership"],
+            RoomVersionId::V11 => AllowedKeys::SomeNested(
+                &["membership", "join_authorised_via_users_server"],
+                &[("third_party_invite", &["signed"])],
+            ),
+            _ => AllowedKeys::Some(&["membership"]),
         },
-        "m.room.create" => &["creator"],
+        "m.room.create" if version.redaction_keeps_all_create_content() => AllowedKeys::All,
+        "m.room.create" if version.redaction_keeps_create_creator() => {
+            AllowedKeys::Some(&["creator"])
+        }
         "m.room.join_rules" => match version {
-            RoomVersionId::V8 | RoomVersionId::V9 | RoomVersionId::V10 => &["join_rule", "allow"],
-            _ => &["join_rule"],
+            RoomVersionId::V8 | RoomVersionId::V9 | RoomVersionId::V10 | RoomVersionId::V11 => {
+                AllowedKeys::Some(&["join_rule", "allow"])
+            }
+            _ => AllowedKeys::Some(&["join_rule"]),
+        },
+        "m.room.power_levels" => match version {
+            RoomVersionId::V11 => AllowedKeys::Some(&[
+                "ban",
+                "events",
+                "events_default",
+                "invite",
+                "kick",
+                "redact",
+                "state_default",
+                "users",
+                "users_default",
+            ]),
+            _ => AllowedKeys::Some(&[
+                "ban",
+                "events",
+                "events_default",
+                "kick",
+                "redact",
+                "state_default",
+                "users",
+                "users_default",
+            ]),
         },
-        "m.room.power_levels" => &[
-            "ban",
-            "events",
-            "events_default",
-            "kick",
-            "redact",
-            "state_default",
-            "users",
-            "users_default",
-        ],
         "m.room.aliases" => match version {
             RoomVersionId::V1
             | RoomVersionId::V2
             | RoomVersionId::V3
             | RoomVersionId::V4
-            | RoomVersionId::V5 => &["aliases"],
+            | RoomVersionId::V5 => AllowedKeys::Some(&["aliases"]),
             // All other room versions, including custom ones, are treated by version 6 rules.
             // TODO: Should we return an error for unknown versions instead?
-            _ => &[],
+            _ => AllowedKeys::Some(&[]),
         },
+        "m.room.redaction" if version.redacts_in_content() => AllowedKeys::Some(&["redacts"]),
         #[cfg(feature = "unstable-msc2870")]
         "m.room.server_acl" if version.as_str() == "org.matrix.msc2870" => {
-            &["allow", "deny", "allow_ip_literals"]
+            AllowedKeys::Some(&["allow", "deny", "allow_ip_literals"])
         }
-        "m.room.history_visibility" => &["history_visibility"],
-        _ => &[],
+        "m.room.history_visibility" => AllowedKeys::Some(&["history_visibility"]),
+        _ => AllowedKeys::Some(&[]),
     }
 }
 
@@ -319,9 +450,15 @@ mod tests {
     use std::collections::BTreeMap;
 
     use js_int::int;
-    use serde_json::{from_str as from_json_str, json, to_string as to_json_string};
+    use serde_json::{
+        from_str as from_json_str, from_value as from_json_value, json, to_string as to_json_string,
+    };
 
-    use super::{to_canonical_value, try_from_json_map, value::CanonicalJsonValue};
+    use super::{
+        redact, redact_json_content_in_place, to_canonical_json_string, to_canonical_value,
+        try_from_json_map, value::CanonicalJsonValue, CanonicalJsonError, CanonicalJsonObject,
+    };
+    use crate::RoomVersionId;
 
     #[test]
     fn serialize_canon() {
@@ -411,4 +548,191 @@ mod tests {
 
         assert_eq!(to_canonical_value(t).unwrap(), CanonicalJsonValue::Object(expected));
     }
+
+    #[test]
+    fn canonical_json_string_spec_examples() {
+        assert_eq!(to_canonical_json_string(&json!({})).unwrap(), "{}");
+        assert_eq!(
+            to_canonical_json_string(&json!({ "one": 1, "two": "Two" })).unwrap(),
+            r#"{"one":1,"two":"Two"}"#
+        );
+        assert_eq!(
+            to_canonical_json_string(&json!({ "b": "2", "a": "1" })).unwrap(),
+            r#"{"a":"1","b":"2"}"#
+        );
+        assert_eq!(
+            to_canonical_json_string(
+                &from_json_str::<serde_json::Value>(r#"{"b":"2","a":"1"}"#).unwrap()
+            )
+            .unwrap(),
+            r#"{"a":"1","b":"2"}"#
+        );
+        assert_eq!(
+            to_canonical_json_string(&json!({ "a": "日本語" })).unwrap(),
+            r#"{"a":"日本語"}"#
+        );
+        assert_eq!(
+            to_canonical_json_string(&json!({ "本": 2, "日": 1 })).unwrap(),
+            r#"{"日":1,"本":2}"#
+        );
+        assert_eq!(
+            to_canonical_json_string(
+                &from_json_str::<serde_json::Value>(r#"{"a":"\u65E5"}"#).unwrap()
+            )
+            .unwrap(),
+            r#"{"a":"日"}"#
+        );
+        assert_eq!(to_canonical_json_string(&json!({ "a": null })).unwrap(), r#"{"a":null}"#);
+    }
+
+    #[test]
+    fn canonical_json_string_rejects_floats() {
+        #[derive(serde::Serialize)]
+        struct Thing {
+            value: f64,
+        }
+
+        assert!(matches!(
+            to_canonical_json_string(&Thing { value: 1.5 }),
+            Err(CanonicalJsonError::FloatNumber)
+        ));
+        assert!(matches!(
+            to_canonical_json_string(&Thing { value: f64::NAN }),
+            Err(CanonicalJsonError::FloatNumber)
+        ));
+        assert!(matches!(
+            to_canonical_json_string(&[f64::INFINITY]),
+            Err(CanonicalJsonError::FloatNumber)
+        ));
+        assert!(matches!(
+            to_canonical_json_string(&json!({ "a": 1.0 })),
+            Err(CanonicalJsonError::FloatNumber)
+        ));
+        assert!(matches!(to_canonical_json_string(&u64::MAX), Err(CanonicalJsonError::IntConvert)));
+
+        // `to_canonical_value` goes through `serde_json::Value`, which converts NaN to `null`.
+        assert_eq!(to_canonical_value(f64::NAN).unwrap(), CanonicalJsonValue::Null);
+    }
+
+    #[test]
+    fn canonical_json_string_raw_value() {
+        let raw = serde_json::value::RawValue::from_string(r#"{ "b": 2, "a": [1, 2] }"#.to_owned())
+            .unwrap();
+        assert_eq!(to_canonical_json_string(&raw).unwrap(), r#"{"a":[1,2],"b":2}"#);
+
+        let raw = serde_json::value::RawValue::from_string(r#"{ "a": 0.5 }"#.to_owned()).unwrap();
+        to_canonical_json_string(&raw).unwrap_err();
+    }
+
+    #[test]
+    fn redact_json_content_member() {
+        let mut content = json!({
+            "membership": "join",
+            "displayname": "Alice",
+            "avatar_url": "mxc://example.org/abcdef",
+        });
+        let object = content.as_object_mut().unwrap();
+
+        redact_json_content_in_place(object, &RoomVersionId::V1, "m.room.member");
+
+        assert_eq!(content, json!({ "membership": "join" }));
+    }
+
+    #[test]
+    fn redact_json_content_member_v11() {
+        let mut content = json!({
+            "membership": "invite",
+            "displayname": "Alice",
+            "third_party_invite": {
+                "display_name": "alice",
+                "signed": {
+                    "mxid": "@alice:example.org",
+                    "token": "abc123",
+                },
+            },
+        });
+        let object = content.as_object_mut().unwrap();
+
+        redact_json_content_in_place(object, &RoomVersionId::V11, "m.room.member");
+
+        assert_eq!(
+            content,
+            json!({
+                "membership": "invite",
+                "third_party_invite": {
+                    "signed": {
+                        "mxid": "@alice:example.org",
+                        "token": "abc123",
+                    },
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn redact_top_level_keys() {
+        let event: CanonicalJsonObject = from_json_value(json!({
+            "type": "m.room.member",
+            "content": { "membership": "join" },
+            "event_id": "$abcdef:example.org",
+            "membership": "join",
+            "origin": "example.org",
+            "origin_server_ts": 1,
+            "prev_state": [],
+            "room_id": "!room:example.org",
+            "sender": "@alice:example.org",
+            "state_key": "@alice:example.org",
+            "unsigned": { "age": 10 },
+        }))
+        .unwrap();
+
+        let redacted = redact(event.clone(), &RoomVersionId::V10, None).unwrap();
+        assert!(redacted.contains_key("origin"));
+        assert!(redacted.contains_key("membership"));
+        assert!(redacted.contains_key("prev_state"));
+        assert!(!redacted.contains_key("unsigned"));
+
+        let redacted = redact(event, &RoomVersionId::V11, None).unwrap();
+        assert!(!redacted.contains_key("origin"));
+        assert!(!redacted.contains_key("membership"));
+        assert!(!redacted.contains_key("prev_state"));
+        assert!(!redacted.contains_key("unsigned"));
+        assert!(redacted.contains_key("origin_server_ts"));
+    }
+
+    #[test]
+    fn redact_json_content_create() {
+        let original = json!({
+            "creator": "@alice:example.org",
+            "m.federate": false,
+            "room_version": "1",
+        });
+
+        let custom = RoomVersionId::try_from("io.ruma.1").unwrap();
+        for version in [RoomVersionId::V1, RoomVersionId::V10, custom] {
+            let mut content = original.clone();
+            let object = content.as_object_mut().unwrap();
+
+            redact_json_content_in_place(object, &version, "m.room.create");
+
+            assert_eq!(content, json!({ "creator": "@alice:example.org" }));
+        }
+
+        let mut content = original.clone();
+        let object = content.as_object_mut().unwrap();
+
+        redact_json_content_in_place(object, &RoomVersionId::V11, "m.room.create");
+
+        assert_eq!(content, original);
+    }
+
+    #[test]
+    fn redact_json_content_unknown_type() {
+        let mut content = json!({ "body": "secret" });
+        let object = content.as_object_mut().unwrap();
+
+        redact_json_content_in_place(object, &RoomVersionId::V10, "m.room.message");
+
+        assert_eq!(content, json!({}));
+    }
 }
@@ -0,0 +1,356 @@
+//! A serializer producing a [`CanonicalJsonValue`] directly.
+//!
+//! Unlike going through `serde_json::to_value`, this rejects floating-point numbers, including
+//! NaN and infinite values that `serde_json` would silently convert to `null`.
+
+use js_int::Int;
+use serde::{ser, Serialize};
+
+use super::{CanonicalJsonError, CanonicalJsonObject, CanonicalJsonValue};
+
+/// The name `serde_json` uses for the struct wrapping a `RawValue` during serialization.
+const RAW_VALUE_TOKEN: &str = "$serde_json::private::RawValue";
+
+impl ser::Error for CanonicalJsonError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Self::SerDe(ser::Error::custom(msg))
+    }
+}
+
+fn int<T: TryInto<Int>>(value: T) -> Result<CanonicalJsonValue, CanonicalJsonError> {
+    value.try_into().map(CanonicalJsonValue::Integer).map_err(|_| CanonicalJsonError::IntConvert)
+}
+
+/// Serializer whose output is a `CanonicalJsonValue`.
+pub(super) struct Serializer;
+
+impl ser::Serializer for Serializer {
+    type Ok = CanonicalJsonValue;
+    type Error = CanonicalJsonError;
+
+    type SerializeSeq = SerializeVec;
+    type SerializeTuple = SerializeVec;
+    type SerializeTupleStruct = SerializeVec;
+    type SerializeTupleVariant = SerializeTupleVariant;
+    type SerializeMap = SerializeMap;
+    type SerializeStruct = SerializeStruct;
+    type SerializeStructVariant = SerializeStructVariant;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(CanonicalJsonValue::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        int(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        int(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        int(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        int(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        int(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        int(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        int(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        int(v)
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<Self::Ok, Self::Error> {
+        Err(CanonicalJsonError::FloatNumber)
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<Self::Ok, Self::Error> {
+        Err(CanonicalJsonError::FloatNumber)
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        Ok(CanonicalJsonValue::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(CanonicalJsonValue::String(v.to_owned()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Ok(CanonicalJsonValue::Array(
+            v.iter().map(|&b| CanonicalJsonValue::Integer(b.into())).collect(),
+        ))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(CanonicalJsonValue::Null)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(CanonicalJsonValue::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(CanonicalJsonValue::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(CanonicalJsonValue::String(variant.to_owned()))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        let mut object = CanonicalJsonObject::new();
+        object.insert(variant.to_owned(), value.serialize(Serializer)?);
+        Ok(CanonicalJsonValue::Object(object))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(SerializeVec { vec: Vec::with_capacity(len.unwrap_or(0)) })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(SerializeTupleVariant { name: variant, vec: Vec::with_capacity(len) })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(SerializeMap { object: CanonicalJsonObject::new(), next_key: None })
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        if name == RAW_VALUE_TOKEN {
+            Ok(SerializeStruct::RawValue(None))
+        } else {
+            Ok(SerializeStruct::Object(CanonicalJsonObject::new()))
+        }
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(SerializeStructVariant { name: variant, object: CanonicalJsonObject::new() })
+    }
+}
+
+pub(super) struct SerializeVec {
+    vec: Vec<CanonicalJsonValue>,
+}
+
+impl ser::SerializeSeq for SerializeVec {
+    type Ok = CanonicalJsonValue;
+    type Error = CanonicalJsonError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.vec.push(value.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(CanonicalJsonValue::Array(self.vec))
+    }
+}
+
+impl ser::SerializeTuple for SerializeVec {
+    type Ok = CanonicalJsonValue;
+    type Error = CanonicalJsonError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeVec {
+    type Ok = CanonicalJsonValue;
+    type Error = CanonicalJsonError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+pub(super) struct SerializeTupleVariant {
+    name: &'static str,
+    vec: Vec<CanonicalJsonValue>,
+}
+
+impl ser::SerializeTupleVariant for SerializeTupleVariant {
+    type Ok = CanonicalJsonValue;
+    type Error = CanonicalJsonError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.vec.push(value.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        let mut object = CanonicalJsonObject::new();
+        object.insert(self.name.to_owned(), CanonicalJsonValue::Array(self.vec));
+        Ok(CanonicalJsonValue::Object(object))
+    }
+}
+
+pub(super) struct SerializeMap {
+    object: CanonicalJsonObject,
+    next_key: Option<String>,
+}
+
+impl ser::SerializeMap for SerializeMap {
+    type Ok = CanonicalJsonValue;
+    type Error = CanonicalJsonError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        // Like `serde_json`, accept keys that serialize to a string, an integer or a boolean.
+        let key = match key.serialize(Serializer)? {
+            CanonicalJsonValue::String(key) => key,
+            CanonicalJsonValue::Integer(key) => key.to_string(),
+            CanonicalJsonValue::Bool(key) => key.to_string(),
+            _ => return Err(ser::Error::custom("key must be a string")),
+        };
+        self.next_key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self.next_key.take().expect("serialize_value called before serialize_key");
+        self.object.insert(key, value.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(CanonicalJsonValue::Object(self.object))
+    }
+}
+
+pub(super) enum SerializeStruct {
+    Object(CanonicalJsonObject),
+    RawValue(Option<CanonicalJsonValue>),
+}
+
+impl ser::SerializeStruct for SerializeStruct {
+    type Ok = CanonicalJsonValue;
+    type Error = CanonicalJsonError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::Object(object) => {
+                object.insert(key.to_owned(), value.serialize(Serializer)?);
+            }
+            Self::RawValue(raw_value) => {
+                // The single field of the raw value wrapper is the JSON string.
+                let json = match value.serialize(Serializer)? {
+                    CanonicalJsonValue::String(json) => json,
+                    _ => return Err(ser::Error::custom("expected raw JSON string")),
+                };
+                *raw_value = Some(serde_json::from_str(&json).map_err(CanonicalJsonError::SerDe)?);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        match self {
+            Self::Object(object) => Ok(CanonicalJsonValue::Object(object)),
+            Self::RawValue(raw_value) => {
+                raw_value.ok_or_else(|| ser::Error::custom("missing raw JSON string"))
+            }
+        }
+    }
+}
+
+pub(super) struct SerializeStructVariant {
+    name: &'static str,
+    object: CanonicalJsonObject,
+}
+
+impl ser::SerializeStructVariant for SerializeStructVariant {
+    type Ok = CanonicalJsonValue;
+    type Error = CanonicalJsonError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.object.insert(key.to_owned(), value.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        let mut object = CanonicalJsonObject::new();
+        object.insert(self.name.to_owned(), CanonicalJsonValue::Object(self.object));
+        Ok(CanonicalJsonValue::Object(object))
+    }
+}
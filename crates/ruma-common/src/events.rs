@@ -112,6 +112,7 @@ pub mod _custom;
 mod content;
 mod enums;
 mod kinds;
+mod ordering;
 mod state_key;
 mod unsigned;
 
@@ -171,6 +172,7 @@ pub use self::{
     content::*,
     enums::*,
     kinds::*,
+    ordering::{order_by_ts_then_id, TimelineOrder},
     relation::{BundledMessageLikeRelations, BundledStateRelations},
     state_key::EmptyStateKey,
     unsigned::{MessageLikeUnsigned, RedactedUnsigned, StateUnsigned, UnsignedRoomRedactionEvent},
@@ -38,6 +38,15 @@ where
 pub trait StaticEventContent: EventContent {
     /// The event type.
     const TYPE: &'static str;
+
+    /// Other event types that are accepted for deserialization, like the stable name of an event
+    /// type that is still using its unstable name.
+    const ALIASES: &'static [&'static str] = &[];
+
+    /// Whether the given string is the event type or one of the aliases of this content.
+    fn matches_type(s: &str) -> bool {
+        s == Self::TYPE || Self::ALIASES.contains(&s)
+    }
 }
 
 /// Content of a global account-data event.
@@ -100,6 +109,11 @@ where
     T: EventContent + DeserializeOwned,
 {
     fn from_parts(_event_type: &str, content: &RawJsonValue) -> serde_json::Result<Self> {
+        #[cfg(feature = "strict-deserialize")]
+        if crate::serde::denying_unknown_fields() {
+            return crate::serde::from_json_str_deny_unknown_fields(content.get());
+        }
+
         from_json_str(content.get())
     }
 }
@@ -2,10 +2,16 @@ use ruma_macros::{event_enum, EventEnumFromEvent};
 use serde::{de, Deserialize};
 use serde_json::value::RawValue as RawJsonValue;
 
-use super::room::encrypted;
+use super::{
+    room::{
+        encrypted,
+        redaction::{OriginalRoomRedactionEvent, RoomRedactionEventContent},
+    },
+    MessageLikeUnsigned,
+};
 use crate::{
-    serde::from_raw_json_value, EventId, MilliSecondsSinceUnixEpoch, OwnedRoomId, RoomId,
-    TransactionId, UserId,
+    serde::from_raw_json_value, EventId, MilliSecondsSinceUnixEpoch, OwnedEventId, OwnedRoomId,
+    RoomId, RoomVersionId, TransactionId, UserId,
 };
 
 event_enum! {
@@ -287,6 +293,36 @@ impl<'de> Deserialize<'de> for AnySyncTimelineEvent {
     }
 }
 
+impl AnyMessageLikeEvent {
+    /// Creates an `m.room.redaction` event that redacts this event.
+    ///
+    /// The redaction is sent by `redacter` with the given event ID and reason, and its
+    /// `origin_server_ts` is the current time. The ID of this event is put at the top level of the
+    /// redaction event, and also in its content starting from room version 11.
+    pub fn redact(
+        &self,
+        reason: Option<String>,
+        redacter: &UserId,
+        redaction_event_id: OwnedEventId,
+        room_version: &RoomVersionId,
+    ) -> OriginalRoomRedactionEvent {
+        let redacts = self.event_id().to_owned();
+        let mut content =
+            RoomRedactionEventContent::for_room_version(redacts.clone(), room_version);
+        content.reason = reason;
+
+        OriginalRoomRedactionEvent {
+            content,
+            redacts,
+            event_id: redaction_event_id,
+            sender: redacter.to_owned(),
+            origin_server_ts: MilliSecondsSinceUnixEpoch::now(),
+            room_id: self.room_id().to_owned(),
+            unsigned: MessageLikeUnsigned::new(),
+        }
+    }
+}
+
 impl AnyMessageLikeEventContent {
     /// Get a copy of the event's `m.relates_to` field, if any.
     ///
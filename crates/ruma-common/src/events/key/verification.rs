@@ -11,6 +11,10 @@ use std::time::Duration;
 
 use crate::{serde::StringEnum, PrivOwnedStr};
 
+mod sas_emoji;
+
+pub use self::sas_emoji::{emoji_string, sas_emoji};
+
 pub mod accept;
 pub mod cancel;
 pub mod done;
@@ -126,9 +130,11 @@ pub enum VerificationMethod {
 
 #[cfg(test)]
 mod tests {
-    use serde_json::{from_value as from_json_value, json};
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
-    use super::{KeyAgreementProtocol, MessageAuthenticationCode};
+    use super::{
+        HashAlgorithm, KeyAgreementProtocol, MessageAuthenticationCode, ShortAuthenticationString,
+    };
 
     #[test]
     fn serialize_key_agreement() {
@@ -172,4 +178,55 @@ mod tests {
         assert_eq!(serialized, "\"hkdf-hmac-sha256.v2\"");
         assert_eq!(deserialized, MessageAuthenticationCode::HkdfHmacSha256V2);
     }
+
+    #[test]
+    #[allow(deprecated)]
+    fn display_from_str_round_trip() {
+        assert_eq!(HashAlgorithm::Sha256.to_string(), "sha256");
+        assert_eq!("sha256".parse::<HashAlgorithm>().unwrap(), HashAlgorithm::Sha256);
+
+        for (value, s) in [
+            (KeyAgreementProtocol::Curve25519, "curve25519"),
+            (KeyAgreementProtocol::Curve25519HkdfSha256, "curve25519-hkdf-sha256"),
+        ] {
+            assert_eq!(value.to_string(), s);
+            assert_eq!(s.parse::<KeyAgreementProtocol>().unwrap(), value);
+        }
+
+        for (value, s) in [
+            (MessageAuthenticationCode::HkdfHmacSha256, "hkdf-hmac-sha256"),
+            (MessageAuthenticationCode::HkdfHmacSha256V2, "hkdf-hmac-sha256.v2"),
+            (MessageAuthenticationCode::HmacSha256, "hmac-sha256"),
+        ] {
+            assert_eq!(value.to_string(), s);
+            assert_eq!(s.parse::<MessageAuthenticationCode>().unwrap(), value);
+        }
+
+        for (value, s) in [
+            (ShortAuthenticationString::Decimal, "decimal"),
+            (ShortAuthenticationString::Emoji, "emoji"),
+        ] {
+            assert_eq!(value.to_string(), s);
+            assert_eq!(s.parse::<ShortAuthenticationString>().unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn custom_values_round_trip() {
+        let hash: HashAlgorithm = from_json_value(json!("sha512")).unwrap();
+        assert_eq!(hash.as_str(), "sha512");
+        assert_eq!(to_json_value(&hash).unwrap(), json!("sha512"));
+
+        let protocol: KeyAgreementProtocol = from_json_value(json!("x448")).unwrap();
+        assert_eq!(protocol.as_str(), "x448");
+        assert_eq!(to_json_value(&protocol).unwrap(), json!("x448"));
+
+        let mac: MessageAuthenticationCode = from_json_value(json!("hkdf-hmac-sha512")).unwrap();
+        assert_eq!(mac.as_str(), "hkdf-hmac-sha512");
+        assert_eq!(to_json_value(&mac).unwrap(), json!("hkdf-hmac-sha512"));
+
+        let sas: ShortAuthenticationString = from_json_value(json!("words")).unwrap();
+        assert_eq!(sas.as_str(), "words");
+        assert_eq!(to_json_value(&sas).unwrap(), json!("words"));
+    }
 }
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 
 use super::{
+    start::{self, SasV1NegotiationError},
     HashAlgorithm, KeyAgreementProtocol, MessageAuthenticationCode, ShortAuthenticationString,
 };
 use crate::{events::relation::Reference, serde::Base64, OwnedTransactionId};
@@ -118,6 +119,32 @@ pub struct SasV1Content {
     pub commitment: Base64,
 }
 
+impl SasV1Content {
+    /// Creates a new `SasV1Content` accepting the given `m.sas.v1` start content, with the
+    /// algorithms we support and the given commitment.
+    ///
+    /// For each kind of algorithm, the first one of the start content that we support is chosen.
+    /// The short authentication string methods are all the ones supported by both sides.
+    ///
+    /// Returns an error if there is no common algorithm for one of the kinds of algorithms.
+    pub fn from_start(
+        start: &start::SasV1Content,
+        ours: &start::SasV1ContentInit,
+        commitment: Base64,
+    ) -> Result<Self, SasV1NegotiationError> {
+        let mut negotiated = start.negotiate(ours)?;
+
+        // `negotiate` makes sure that the lists are not empty.
+        Ok(Self {
+            key_agreement_protocol: negotiated.key_agreement_protocols.swap_remove(0),
+            hash: negotiated.hashes.swap_remove(0),
+            message_authentication_code: negotiated.message_authentication_codes.swap_remove(0),
+            short_authentication_string: negotiated.short_authentication_string,
+            commitment,
+        })
+    }
+}
+
 /// Mandatory initial set of fields for creating an accept `SasV1Content`.
 #[derive(Debug)]
 #[allow(clippy::exhaustive_structs)]
@@ -169,9 +196,10 @@ mod tests {
     };
 
     use super::{
-        AcceptMethod, HashAlgorithm, KeyAgreementProtocol, KeyVerificationAcceptEventContent,
-        MessageAuthenticationCode, SasV1Content, ShortAuthenticationString,
-        ToDeviceKeyVerificationAcceptEventContent, _CustomContent,
+        start, AcceptMethod, HashAlgorithm, KeyAgreementProtocol,
+        KeyVerificationAcceptEventContent, MessageAuthenticationCode, SasV1Content,
+        SasV1NegotiationError, ShortAuthenticationString, ToDeviceKeyVerificationAcceptEventContent,
+        _CustomContent,
     };
     use crate::{
         event_id,
@@ -179,6 +207,61 @@ mod tests {
         serde::Base64,
     };
 
+    #[test]
+    #[allow(deprecated)]
+    fn from_start() {
+        let ours = start::SasV1ContentInit {
+            key_agreement_protocols: vec![
+                KeyAgreementProtocol::Curve25519HkdfSha256,
+                KeyAgreementProtocol::Curve25519,
+            ],
+            hashes: vec![HashAlgorithm::Sha256],
+            message_authentication_codes: vec![
+                MessageAuthenticationCode::HkdfHmacSha256,
+                MessageAuthenticationCode::HkdfHmacSha256V2,
+            ],
+            short_authentication_string: vec![
+                ShortAuthenticationString::Emoji,
+                ShortAuthenticationString::Decimal,
+            ],
+        };
+
+        let accept = SasV1Content::from_start(
+            &start::SasV1Content::with_defaults(),
+            &ours,
+            Base64::new(b"hello".to_vec()),
+        )
+        .unwrap();
+        assert_eq!(accept.key_agreement_protocol, KeyAgreementProtocol::Curve25519HkdfSha256);
+        assert_eq!(accept.hash, HashAlgorithm::Sha256);
+        assert_eq!(accept.message_authentication_code, MessageAuthenticationCode::HkdfHmacSha256V2);
+        assert_eq!(
+            accept.short_authentication_string,
+            vec![ShortAuthenticationString::Decimal, ShortAuthenticationString::Emoji]
+        );
+        assert_eq!(accept.commitment.as_bytes(), b"hello");
+    }
+
+    #[test]
+    fn from_start_no_common_hash() {
+        let ours = start::SasV1ContentInit {
+            key_agreement_protocols: vec![KeyAgreementProtocol::Curve25519],
+            hashes: vec!["sha512".into()],
+            message_authentication_codes: vec![MessageAuthenticationCode::HkdfHmacSha256V2],
+            short_authentication_string: vec![ShortAuthenticationString::Decimal],
+        };
+
+        assert_eq!(
+            SasV1Content::from_start(
+                &start::SasV1Content::with_defaults(),
+                &ours,
+                Base64::new(b"hello".to_vec()),
+            )
+            .unwrap_err(),
+            SasV1NegotiationError::Hash
+        );
+    }
+
     #[test]
     fn serialization() {
         let key_verification_accept_content = ToDeviceKeyVerificationAcceptEventContent {
@@ -0,0 +1,110 @@
+//! The emoji representation of a Short Authentication String.
+//!
+//! See the [spec] for the table of emoji and their descriptions.
+//!
+//! [spec]: https://spec.matrix.org/latest/client-server-api/#sas-method-emoji
+
+/// The emoji and their English descriptions, indexed by the 6-bit numbers generated from the SAS.
+const SAS_EMOJI: [(&str, &str); 64] = [
+    ("🐶", "Dog"),
+    ("🐱", "Cat"),
+    ("🦁", "Lion"),
+    ("🐎", "Horse"),
+    ("🦄", "Unicorn"),
+    ("🐷", "Pig"),
+    ("🐘", "Elephant"),
+    ("🐰", "Rabbit"),
+    ("🐼", "Panda"),
+    ("🐓", "Rooster"),
+    ("🐧", "Penguin"),
+    ("🐢", "Turtle"),
+    ("🐟", "Fish"),
+    ("🐙", "Octopus"),
+    ("🦋", "Butterfly"),
+    ("🌷", "Flower"),
+    ("🌳", "Tree"),
+    ("🌵", "Cactus"),
+    ("🍄", "Mushroom"),
+    ("🌏", "Globe"),
+    ("🌙", "Moon"),
+    ("☁️", "Cloud"),
+    ("🔥", "Fire"),
+    ("🍌", "Banana"),
+    ("🍎", "Apple"),
+    ("🍓", "Strawberry"),
+    ("🌽", "Corn"),
+    ("🍕", "Pizza"),
+    ("🎂", "Cake"),
+    ("❤️", "Heart"),
+    ("😀", "Smiley"),
+    ("🤖", "Robot"),
+    ("🎩", "Hat"),
+    ("👓", "Glasses"),
+    ("🔧", "Spanner"),
+    ("🎅", "Santa"),
+    ("👍", "Thumbs Up"),
+    ("☂️", "Umbrella"),
+    ("⌛", "Hourglass"),
+    ("⏰", "Clock"),
+    ("🎁", "Gift"),
+    ("💡", "Light Bulb"),
+    ("📕", "Book"),
+    ("✏️", "Pencil"),
+    ("📎", "Paperclip"),
+    ("✂️", "Scissors"),
+    ("🔒", "Lock"),
+    ("🔑", "Key"),
+    ("🔨", "Hammer"),
+    ("☎️", "Telephone"),
+    ("🏁", "Flag"),
+    ("🚂", "Train"),
+    ("🚲", "Bicycle"),
+    ("✈️", "Aeroplane"),
+    ("🚀", "Rocket"),
+    ("🏆", "Trophy"),
+    ("⚽", "Ball"),
+    ("🎸", "Guitar"),
+    ("🎺", "Trumpet"),
+    ("🔔", "Bell"),
+    ("⚓", "Anchor"),
+    ("🎧", "Headphones"),
+    ("📁", "Folder"),
+    ("📌", "Pin"),
+];
+
+/// Get the emoji and its English description for the given SAS index.
+///
+/// Returns `None` if the index is not in the range `0..64`.
+pub fn sas_emoji(index: u8) -> Option<(&'static str, &'static str)> {
+    SAS_EMOJI.get(usize::from(index)).copied()
+}
+
+/// Get the emoji for the given SAS indices, separated by spaces.
+///
+/// Returns `None` if any of the indices is not in the range `0..64`.
+pub fn emoji_string(indices: &[u8]) -> Option<String> {
+    let emoji = indices
+        .iter()
+        .map(|&index| sas_emoji(index).map(|(emoji, _)| emoji))
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(emoji.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{emoji_string, sas_emoji};
+
+    #[test]
+    fn sas_emoji_lookup() {
+        assert_eq!(sas_emoji(0), Some(("🐶", "Dog")));
+        assert_eq!(sas_emoji(63), Some(("📌", "Pin")));
+        assert_eq!(sas_emoji(64), None);
+    }
+
+    #[test]
+    fn sas_emoji_string() {
+        assert_eq!(emoji_string(&[0, 1, 2, 3, 4, 5, 6]).as_deref(), Some("🐶 🐱 🦁 🐎 🦄 🐷 🐘"));
+        assert_eq!(emoji_string(&[0, 64]), None);
+    }
+}
@@ -45,6 +45,14 @@ impl ToDeviceKeyVerificationStartEventContent {
     ) -> Self {
         Self { from_device, transaction_id, method }
     }
+
+    /// Creates a new `ToDeviceKeyVerificationStartEventContent` with the given device ID and
+    /// transaction ID, using the `m.sas.v1` method with the default set of algorithms.
+    ///
+    /// See [`SasV1Content::with_defaults`] for the algorithms that are used.
+    pub fn with_sas_v1(from_device: OwnedDeviceId, transaction_id: OwnedTransactionId) -> Self {
+        Self::new(from_device, transaction_id, StartMethod::SasV1(SasV1Content::with_defaults()))
+    }
 }
 
 /// The content of an in-room `m.key.verification.start` event.
@@ -72,6 +80,14 @@ impl KeyVerificationStartEventContent {
     pub fn new(from_device: OwnedDeviceId, method: StartMethod, relates_to: Reference) -> Self {
         Self { from_device, method, relates_to }
     }
+
+    /// Creates a new `KeyVerificationStartEventContent` with the given device ID and reference,
+    /// using the `m.sas.v1` method with the default set of algorithms.
+    ///
+    /// See [`SasV1Content::with_defaults`] for the algorithms that are used.
+    pub fn with_sas_v1(from_device: OwnedDeviceId, relates_to: Reference) -> Self {
+        Self::new(from_device, StartMethod::SasV1(SasV1Content::with_defaults()), relates_to)
+    }
 }
 
 /// An enum representing the different method specific `m.key.verification.start` content.
@@ -94,6 +110,58 @@ pub enum StartMethod {
     _Custom(_CustomContent),
 }
 
+impl StartMethod {
+    /// Creates a new `StartMethod` skeleton for the verification method with the given name.
+    ///
+    /// For `m.sas.v1`, this uses the default set of algorithms of [`SasV1Content::with_defaults`].
+    /// For `m.reciprocate.v1`, the secret is empty and must be replaced with the one from the
+    /// scanned QR code. Any other method name results in a custom method without additional
+    /// fields.
+    pub fn from_method_name(name: &str) -> Self {
+        match name {
+            "m.sas.v1" => Self::SasV1(SasV1Content::with_defaults()),
+            "m.reciprocate.v1" => {
+                Self::ReciprocateV1(ReciprocateV1Content::new(Base64::new(Vec::new())))
+            }
+            _ => Self::_Custom(_CustomContent { method: name.to_owned(), data: BTreeMap::new() }),
+        }
+    }
+
+    /// Returns the name of the verification method, like `m.sas.v1`.
+    pub fn method_name(&self) -> &str {
+        match self {
+            Self::SasV1(_) => "m.sas.v1",
+            Self::ReciprocateV1(_) => "m.reciprocate.v1",
+            Self::_Custom(c) => &c.method,
+        }
+    }
+
+    /// Whether this is a verification method that is not known by ruma.
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Self::_Custom(_))
+    }
+}
+
+/// Prints the name of the method and, for `m.sas.v1`, the supported short authentication strings,
+/// like `m.sas.v1 (decimal, emoji)`.
+impl fmt::Display for StartMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.method_name())?;
+
+        if let Self::SasV1(content) = self {
+            if let Some((first, rest)) = content.short_authentication_string.split_first() {
+                write!(f, " ({first}")?;
+                for sas in rest {
+                    write!(f, ", {sas}")?;
+                }
+                f.write_str(")")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Method specific content of a unknown key verification method.
 #[doc(hidden)]
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -107,7 +175,7 @@ pub struct _CustomContent {
     pub data: BTreeMap<String, JsonValue>,
 }
 
-/// The payload of an `m.key.verification.start` event using the `m.sas.v1` method.
+/// The payload of an `m.key.verification.start` event using the `m.reciprocate.v1` method.
 #[derive(Clone, Deserialize, Serialize)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
 #[serde(rename = "m.reciprocate.v1", tag = "method")]
@@ -162,6 +230,99 @@ pub struct SasV1Content {
     pub short_authentication_string: Vec<ShortAuthenticationString>,
 }
 
+impl SasV1Content {
+    /// Creates a new `SasV1Content` with the algorithms required by the spec.
+    ///
+    /// This uses the following algorithms:
+    ///
+    /// * key agreement protocols: `curve25519-hkdf-sha256` and, for compatibility with older
+    ///   clients, `curve25519`
+    /// * hashes: `sha256`
+    /// * message authentication codes: `hkdf-hmac-sha256.v2` and, for compatibility with older
+    ///   clients, `hkdf-hmac-sha256`
+    /// * short authentication strings: `decimal` and `emoji`
+    pub fn with_defaults() -> Self {
+        Self {
+            key_agreement_protocols: vec![
+                KeyAgreementProtocol::Curve25519HkdfSha256,
+                KeyAgreementProtocol::Curve25519,
+            ],
+            hashes: vec![HashAlgorithm::Sha256],
+            #[allow(deprecated)]
+            message_authentication_codes: vec![
+                MessageAuthenticationCode::HkdfHmacSha256V2,
+                MessageAuthenticationCode::HkdfHmacSha256,
+            ],
+            short_authentication_string: vec![
+                ShortAuthenticationString::Decimal,
+                ShortAuthenticationString::Emoji,
+            ],
+        }
+    }
+
+    /// Computes the algorithms supported by both the sender of this content and us.
+    ///
+    /// The algorithms of the returned content are in the order of preference of the sender.
+    ///
+    /// Returns an error if there is no common algorithm for one of the lists.
+    pub fn negotiate(&self, ours: &SasV1ContentInit) -> Result<Self, SasV1NegotiationError> {
+        fn intersection<T: Clone + PartialEq>(theirs: &[T], ours: &[T]) -> Vec<T> {
+            theirs.iter().filter(|value| ours.contains(value)).cloned().collect()
+        }
+
+        let key_agreement_protocols =
+            intersection(&self.key_agreement_protocols, &ours.key_agreement_protocols);
+        if key_agreement_protocols.is_empty() {
+            return Err(SasV1NegotiationError::KeyAgreementProtocol);
+        }
+
+        let hashes = intersection(&self.hashes, &ours.hashes);
+        if hashes.is_empty() {
+            return Err(SasV1NegotiationError::Hash);
+        }
+
+        let message_authentication_codes =
+            intersection(&self.message_authentication_codes, &ours.message_authentication_codes);
+        if message_authentication_codes.is_empty() {
+            return Err(SasV1NegotiationError::MessageAuthenticationCode);
+        }
+
+        let short_authentication_string =
+            intersection(&self.short_authentication_string, &ours.short_authentication_string);
+        if short_authentication_string.is_empty() {
+            return Err(SasV1NegotiationError::ShortAuthenticationString);
+        }
+
+        Ok(Self {
+            key_agreement_protocols,
+            hashes,
+            message_authentication_codes,
+            short_authentication_string,
+        })
+    }
+}
+
+/// An error encountered when negotiating the algorithms of an `SasV1Content`.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum SasV1NegotiationError {
+    /// There is no key agreement protocol supported by both sides.
+    #[error("no common key agreement protocol")]
+    KeyAgreementProtocol,
+
+    /// There is no hash algorithm supported by both sides.
+    #[error("no common hash algorithm")]
+    Hash,
+
+    /// There is no message authentication code supported by both sides.
+    #[error("no common message authentication code")]
+    MessageAuthenticationCode,
+
+    /// There is no short authentication string method supported by both sides.
+    #[error("no common short authentication string method")]
+    ShortAuthenticationString,
+}
+
 /// Mandatory initial set of fields for creating an `SasV1Content`.
 ///
 /// This struct will not be updated even if additional fields are added to `SasV1Content` in a new
@@ -215,16 +376,183 @@ mod tests {
 
     use super::{
         HashAlgorithm, KeyAgreementProtocol, KeyVerificationStartEventContent,
-        MessageAuthenticationCode, ReciprocateV1Content, SasV1ContentInit,
-        ShortAuthenticationString, StartMethod, ToDeviceKeyVerificationStartEventContent,
+        MessageAuthenticationCode, ReciprocateV1Content, SasV1Content, SasV1ContentInit,
+        SasV1NegotiationError, ShortAuthenticationString, StartMethod,
+        ToDeviceKeyVerificationStartEventContent,
         _CustomContent,
     };
     use crate::{
-        event_id,
+        device_id, event_id,
         events::{relation::Reference, ToDeviceEvent},
-        serde::Base64,
+        serde::{Base64, Raw},
     };
 
+    #[test]
+    fn serialization_with_sas_v1_defaults() {
+        let content =
+            ToDeviceKeyVerificationStartEventContent::with_sas_v1("123".into(), "456".into());
+
+        assert_eq!(
+            to_json_value(&content).unwrap(),
+            json!({
+                "from_device": "123",
+                "transaction_id": "456",
+                "method": "m.sas.v1",
+                "key_agreement_protocols": ["curve25519-hkdf-sha256", "curve25519"],
+                "hashes": ["sha256"],
+                "message_authentication_codes": ["hkdf-hmac-sha256.v2", "hkdf-hmac-sha256"],
+                "short_authentication_string": ["decimal", "emoji"]
+            })
+        );
+
+        let content = KeyVerificationStartEventContent::with_sas_v1(
+            "123".into(),
+            Reference::new(event_id!("$1598361704261elfgc:localhost").to_owned()),
+        );
+
+        assert_eq!(
+            to_json_value(&content).unwrap(),
+            json!({
+                "from_device": "123",
+                "method": "m.sas.v1",
+                "key_agreement_protocols": ["curve25519-hkdf-sha256", "curve25519"],
+                "hashes": ["sha256"],
+                "message_authentication_codes": ["hkdf-hmac-sha256.v2", "hkdf-hmac-sha256"],
+                "short_authentication_string": ["decimal", "emoji"],
+                "m.relates_to": {
+                    "rel_type": "m.reference",
+                    "event_id": "$1598361704261elfgc:localhost",
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn negotiate_sas_v1() {
+        let ours = SasV1ContentInit {
+            key_agreement_protocols: vec![
+                KeyAgreementProtocol::Curve25519,
+                KeyAgreementProtocol::Curve25519HkdfSha256,
+            ],
+            hashes: vec![HashAlgorithm::Sha256],
+            message_authentication_codes: vec![MessageAuthenticationCode::HkdfHmacSha256V2],
+            short_authentication_string: vec![ShortAuthenticationString::Emoji],
+        };
+
+        let negotiated = SasV1Content::with_defaults().negotiate(&ours).unwrap();
+        assert_eq!(
+            negotiated.key_agreement_protocols,
+            vec![KeyAgreementProtocol::Curve25519HkdfSha256, KeyAgreementProtocol::Curve25519]
+        );
+        assert_eq!(negotiated.hashes, vec![HashAlgorithm::Sha256]);
+        assert_eq!(
+            negotiated.message_authentication_codes,
+            vec![MessageAuthenticationCode::HkdfHmacSha256V2]
+        );
+        assert_eq!(negotiated.short_authentication_string, vec![ShortAuthenticationString::Emoji]);
+    }
+
+    #[test]
+    fn negotiate_sas_v1_no_common_mac() {
+        let ours = SasV1ContentInit {
+            key_agreement_protocols: vec![KeyAgreementProtocol::Curve25519],
+            hashes: vec![HashAlgorithm::Sha256],
+            message_authentication_codes: vec![MessageAuthenticationCode::HmacSha256],
+            short_authentication_string: vec![ShortAuthenticationString::Decimal],
+        };
+
+        assert_eq!(
+            SasV1Content::with_defaults().negotiate(&ours).unwrap_err(),
+            SasV1NegotiationError::MessageAuthenticationCode
+        );
+    }
+
+    #[test]
+    fn method_name() {
+        let method = StartMethod::SasV1(SasV1Content::with_defaults());
+        assert_eq!(method.method_name(), "m.sas.v1");
+        assert!(!method.is_custom());
+
+        let method = StartMethod::_Custom(_CustomContent {
+            method: "m.sas.custom".to_owned(),
+            data: BTreeMap::new(),
+        });
+        assert_eq!(method.method_name(), "m.sas.custom");
+        assert!(method.is_custom());
+    }
+
+    #[test]
+    fn from_method_name() {
+        let method = StartMethod::from_method_name("m.sas.v1");
+        let sas = assert_matches!(method, StartMethod::SasV1(sas) => sas);
+        assert_eq!(
+            sas.key_agreement_protocols,
+            vec![KeyAgreementProtocol::Curve25519HkdfSha256, KeyAgreementProtocol::Curve25519]
+        );
+        assert_eq!(sas.hashes, vec![HashAlgorithm::Sha256]);
+        assert_eq!(
+            sas.short_authentication_string,
+            vec![ShortAuthenticationString::Decimal, ShortAuthenticationString::Emoji]
+        );
+
+        let method = StartMethod::from_method_name("m.reciprocate.v1");
+        assert_matches!(method, StartMethod::ReciprocateV1(_));
+
+        let method = StartMethod::from_method_name("org.example.custom");
+        assert!(method.is_custom());
+        assert_eq!(method.method_name(), "org.example.custom");
+        assert_eq!(to_json_value(&method).unwrap(), json!({ "method": "org.example.custom" }));
+    }
+
+    #[test]
+    fn display() {
+        let method = StartMethod::SasV1(SasV1Content::with_defaults());
+        assert_eq!(method.to_string(), "m.sas.v1 (decimal, emoji)");
+
+        let method = StartMethod::SasV1(
+            SasV1ContentInit {
+                key_agreement_protocols: vec![KeyAgreementProtocol::Curve25519HkdfSha256],
+                hashes: vec![HashAlgorithm::Sha256],
+                message_authentication_codes: vec![MessageAuthenticationCode::HkdfHmacSha256V2],
+                short_authentication_string: vec![],
+            }
+            .into(),
+        );
+        assert_eq!(method.to_string(), "m.sas.v1");
+
+        let method = StartMethod::ReciprocateV1(ReciprocateV1Content::new(Base64::new(vec![1])));
+        assert_eq!(method.to_string(), "m.reciprocate.v1");
+
+        let method = StartMethod::_Custom(_CustomContent {
+            method: "m.sas.custom".to_owned(),
+            data: BTreeMap::new(),
+        });
+        assert_eq!(method.to_string(), "m.sas.custom");
+    }
+
+    #[test]
+    fn new_with_device_id() {
+        let content = ToDeviceKeyVerificationStartEventContent::new(
+            device_id!("123").to_owned(),
+            "456".into(),
+            StartMethod::SasV1(SasV1Content::with_defaults()),
+        );
+
+        assert_eq!(content.from_device, "123");
+        assert_eq!(
+            to_json_value(&content).unwrap(),
+            json!({
+                "from_device": "123",
+                "transaction_id": "456",
+                "method": "m.sas.v1",
+                "key_agreement_protocols": ["curve25519-hkdf-sha256", "curve25519"],
+                "hashes": ["sha256"],
+                "message_authentication_codes": ["hkdf-hmac-sha256.v2", "hkdf-hmac-sha256"],
+                "short_authentication_string": ["decimal", "emoji"]
+            })
+        );
+    }
+
     #[test]
     fn serialization() {
         let key_verification_start_content = ToDeviceKeyVerificationStartEventContent {
@@ -456,6 +784,50 @@ mod tests {
         assert_eq!(reciprocate.secret.encode(), "c2VjcmV0Cg");
     }
 
+    #[test]
+    fn deserialization_from_raw_value() {
+        let json = json!({
+            "from_device": "123",
+            "transaction_id": "456",
+            "method": "m.sas.v1",
+            "hashes": ["sha256"],
+            "key_agreement_protocols": ["curve25519"],
+            "message_authentication_codes": ["hkdf-hmac-sha256.v2"],
+            "short_authentication_string": ["decimal"]
+        });
+
+        let raw = Raw::<ToDeviceKeyVerificationStartEventContent>::from_value(&json).unwrap();
+        let content = raw.deserialize().unwrap();
+
+        assert_eq!(content.from_device, "123");
+        assert_eq!(content.transaction_id, "456");
+        let sas = assert_matches!(content.method, StartMethod::SasV1(sas) => sas);
+        assert_eq!(sas.hashes, vec![HashAlgorithm::Sha256]);
+        assert_eq!(sas.short_authentication_string, vec![ShortAuthenticationString::Decimal]);
+    }
+
+    #[test]
+    fn strict_deserialization_of_custom_method() {
+        let raw = Raw::<ToDeviceKeyVerificationStartEventContent>::from_json_string(
+            r#"{
+                "from_device": "123",
+                "transaction_id": "456",
+                "method": "m.sas.custom",
+                "test": "field",
+                "test": "other field"
+            }"#
+            .to_owned(),
+        )
+        .unwrap();
+
+        let content = raw.deserialize().unwrap();
+        let custom = assert_matches!(content.method, StartMethod::_Custom(custom) => custom);
+        assert_eq!(custom.data.get("test").unwrap(), "other field");
+
+        let err = raw.deserialize_strict().unwrap_err();
+        assert!(err.to_string().contains("duplicate key `test`"));
+    }
+
     #[test]
     fn in_room_deserialization() {
         let json = json!({
@@ -508,4 +880,27 @@ mod tests {
         );
         assert_eq!(reciprocate.secret.encode(), "c2VjcmV0Cg");
     }
+
+    #[test]
+    fn deserialize_with_raw() {
+        let json = json!({
+            "from_device": "123",
+            "transaction_id": "456",
+            "method": "m.reciprocate.v1",
+            "secret": "c2VjcmV0Cg",
+            "dev.ruma.unknown": { "foo": "bar" },
+        });
+        let raw = Raw::<ToDeviceKeyVerificationStartEventContent>::from_value(&json).unwrap();
+
+        let (content, value) = raw.deserialize_with_raw().unwrap();
+        assert_eq!(content.from_device, "123");
+        assert_eq!(content.transaction_id, "456");
+        let reciprocate = assert_matches!(
+            content.method,
+            StartMethod::ReciprocateV1(reciprocate) => reciprocate
+        );
+        assert_eq!(reciprocate.secret.encode(), "c2VjcmV0Cg");
+
+        assert_eq!(value, json);
+    }
 }
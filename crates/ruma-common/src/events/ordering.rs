@@ -0,0 +1,206 @@
+use std::cmp::Ordering;
+
+use super::{
+    AnyMessageLikeEvent, AnyStateEvent, AnySyncMessageLikeEvent, AnySyncStateEvent,
+    AnySyncTimelineEvent, AnyTimelineEvent, MessageLikeEvent, MessageLikeEventContent,
+    OriginalMessageLikeEvent, OriginalStateEvent, OriginalSyncMessageLikeEvent,
+    OriginalSyncStateEvent, RedactContent, RedactedMessageLikeEventContent,
+    RedactedStateEventContent, StateEvent, StaticStateEventContent, SyncMessageLikeEvent,
+    SyncStateEvent,
+};
+use crate::{EventId, MilliSecondsSinceUnixEpoch};
+
+/// A timeline event that can be ordered with [`order_by_ts_then_id()`].
+pub trait TimelineOrder {
+    /// The timestamp in milliseconds on the originating homeserver when this event was sent.
+    fn origin_server_ts(&self) -> MilliSecondsSinceUnixEpoch;
+
+    /// The globally unique event identifier.
+    fn event_id(&self) -> &EventId;
+}
+
+impl<T: TimelineOrder + ?Sized> TimelineOrder for &T {
+    fn origin_server_ts(&self) -> MilliSecondsSinceUnixEpoch {
+        (**self).origin_server_ts()
+    }
+
+    fn event_id(&self) -> &EventId {
+        (**self).event_id()
+    }
+}
+
+/// Compare two events by their `origin_server_ts`, then by the lexicographic order of their
+/// `event_id` if the timestamps are equal.
+///
+/// This is a stable total order that can be used with [`slice::sort_by`]:
+///
+/// ```
+/// # use ruma_common::events::{order_by_ts_then_id, AnySyncTimelineEvent};
+/// # let mut events: Vec<AnySyncTimelineEvent> = Vec::new();
+/// events.sort_by(order_by_ts_then_id);
+/// ```
+pub fn order_by_ts_then_id<T: TimelineOrder + ?Sized>(a: &T, b: &T) -> Ordering {
+    a.origin_server_ts().cmp(&b.origin_server_ts()).then_with(|| a.event_id().cmp(b.event_id()))
+}
+
+macro_rules! impl_timeline_order_for_accessors {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl TimelineOrder for $ty {
+                fn origin_server_ts(&self) -> MilliSecondsSinceUnixEpoch {
+                    self.origin_server_ts()
+                }
+
+                fn event_id(&self) -> &EventId {
+                    self.event_id()
+                }
+            }
+        )*
+    };
+}
+
+impl_timeline_order_for_accessors!(
+    AnyTimelineEvent,
+    AnySyncTimelineEvent,
+    AnyMessageLikeEvent,
+    AnySyncMessageLikeEvent,
+    AnyStateEvent,
+    AnySyncStateEvent,
+);
+
+impl<C: MessageLikeEventContent> TimelineOrder for OriginalMessageLikeEvent<C> {
+    fn origin_server_ts(&self) -> MilliSecondsSinceUnixEpoch {
+        self.origin_server_ts
+    }
+
+    fn event_id(&self) -> &EventId {
+        &self.event_id
+    }
+}
+
+impl<C: MessageLikeEventContent> TimelineOrder for OriginalSyncMessageLikeEvent<C> {
+    fn origin_server_ts(&self) -> MilliSecondsSinceUnixEpoch {
+        self.origin_server_ts
+    }
+
+    fn event_id(&self) -> &EventId {
+        &self.event_id
+    }
+}
+
+impl<C: StaticStateEventContent> TimelineOrder for OriginalStateEvent<C> {
+    fn origin_server_ts(&self) -> MilliSecondsSinceUnixEpoch {
+        self.origin_server_ts
+    }
+
+    fn event_id(&self) -> &EventId {
+        &self.event_id
+    }
+}
+
+impl<C: StaticStateEventContent> TimelineOrder for OriginalSyncStateEvent<C> {
+    fn origin_server_ts(&self) -> MilliSecondsSinceUnixEpoch {
+        self.origin_server_ts
+    }
+
+    fn event_id(&self) -> &EventId {
+        &self.event_id
+    }
+}
+
+impl<C> TimelineOrder for MessageLikeEvent<C>
+where
+    C: MessageLikeEventContent + RedactContent,
+    C::Redacted: RedactedMessageLikeEventContent,
+{
+    fn origin_server_ts(&self) -> MilliSecondsSinceUnixEpoch {
+        self.origin_server_ts()
+    }
+
+    fn event_id(&self) -> &EventId {
+        self.event_id()
+    }
+}
+
+impl<C> TimelineOrder for SyncMessageLikeEvent<C>
+where
+    C: MessageLikeEventContent + RedactContent,
+    C::Redacted: RedactedMessageLikeEventContent,
+{
+    fn origin_server_ts(&self) -> MilliSecondsSinceUnixEpoch {
+        self.origin_server_ts()
+    }
+
+    fn event_id(&self) -> &EventId {
+        self.event_id()
+    }
+}
+
+impl<C> TimelineOrder for StateEvent<C>
+where
+    C: StaticStateEventContent + RedactContent,
+    C::Redacted: RedactedStateEventContent<StateKey = C::StateKey>,
+{
+    fn origin_server_ts(&self) -> MilliSecondsSinceUnixEpoch {
+        self.origin_server_ts()
+    }
+
+    fn event_id(&self) -> &EventId {
+        self.event_id()
+    }
+}
+
+impl<C> TimelineOrder for SyncStateEvent<C>
+where
+    C: StaticStateEventContent + RedactContent,
+    C::Redacted: RedactedStateEventContent<StateKey = C::StateKey>,
+{
+    fn origin_server_ts(&self) -> MilliSecondsSinceUnixEpoch {
+        self.origin_server_ts()
+    }
+
+    fn event_id(&self) -> &EventId {
+        self.event_id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value as from_json_value, json};
+
+    use super::order_by_ts_then_id;
+    use crate::events::AnySyncTimelineEvent;
+
+    fn event(event_id: &str, ts: u64) -> AnySyncTimelineEvent {
+        from_json_value(json!({
+            "content": { "body": "Hello", "msgtype": "m.text" },
+            "event_id": event_id,
+            "origin_server_ts": ts,
+            "sender": "@alice:example.org",
+            "type": "m.room.message",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn sort_by_ts_then_id() {
+        let mut events = [
+            event("$c:example.org", 2),
+            event("$b:example.org", 1),
+            event("$d:example.org", 0),
+            event("$a:example.org", 1),
+        ];
+        events.sort_by(order_by_ts_then_id);
+
+        let event_ids: Vec<_> = events.iter().map(|ev| ev.event_id().as_str()).collect();
+        assert_eq!(
+            event_ids,
+            ["$d:example.org", "$a:example.org", "$b:example.org", "$c:example.org"]
+        );
+
+        // Works with references too.
+        let mut refs: Vec<_> = events.iter().rev().collect();
+        refs.sort_by(order_by_ts_then_id);
+        assert_eq!(refs[0].event_id().as_str(), "$d:example.org");
+    }
+}
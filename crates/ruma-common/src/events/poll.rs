@@ -4,6 +4,67 @@
 //!
 //! [MSC3381]: https://github.com/matrix-org/matrix-spec-proposals/pull/3381
 
+use std::collections::{BTreeMap, BTreeSet};
+
+use self::{response::SelectionsContentBlock, start::PollContentBlock};
+use crate::{MilliSecondsSinceUnixEpoch, OwnedUserId};
+
 pub mod end;
 pub mod response;
 pub mod start;
+
+/// Compile the results of a poll from the given responses.
+///
+/// `responses` should contain the sender, the `origin_server_ts` and the selections of every
+/// response to the poll. Only the latest response of each user is taken into account, and only
+/// its first `max_selections` selections are counted. Selections that don't match an answer of
+/// the poll are ignored.
+///
+/// Returns a map of answer ID to number of votes, containing all the answers of the poll.
+pub fn compile_poll_results(
+    poll: &PollContentBlock,
+    responses: impl IntoIterator<
+        Item = (OwnedUserId, MilliSecondsSinceUnixEpoch, SelectionsContentBlock),
+    >,
+) -> BTreeMap<String, u64> {
+    let mut latest_responses = BTreeMap::new();
+
+    for (user_id, ts, selections) in responses {
+        match latest_responses.get(&user_id) {
+            Some((latest_ts, _)) if *latest_ts > ts => {}
+            _ => {
+                latest_responses.insert(user_id, (ts, selections));
+            }
+        }
+    }
+
+    let mut results: BTreeMap<String, u64> =
+        poll.answers.iter().map(|answer| (answer.id.clone(), 0)).collect();
+
+    for (_, selections) in latest_responses.values() {
+        // Make sure that a user can't vote several times for the same answer.
+        let selections: BTreeSet<&String> =
+            selections.validate(poll.max_selections).iter().collect();
+
+        for selection in selections {
+            if let Some(count) = results.get_mut(selection) {
+                *count += 1;
+            }
+        }
+    }
+
+    results
+}
+
+/// Filter the given responses to only keep those that were sent before the poll ended.
+///
+/// `end_ts` should be the `origin_server_ts` of the poll end event, and `responses` should
+/// contain the `origin_server_ts` of every response to the poll along with the response. According
+/// to the spec, responses received after the poll end event must not be counted when compiling the
+/// results of the poll.
+pub fn responses_before_end<T>(
+    end_ts: MilliSecondsSinceUnixEpoch,
+    responses: impl IntoIterator<Item = (MilliSecondsSinceUnixEpoch, T)>,
+) -> impl Iterator<Item = T> {
+    responses.into_iter().filter(move |(ts, _)| *ts <= end_ts).map(|(_, response)| response)
+}
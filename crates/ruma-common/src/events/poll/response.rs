@@ -1,7 +1,8 @@
 //! Types for the [`m.poll.response`] event.
 
-use std::{ops::Deref, vec};
+use std::{collections::BTreeSet, ops::Deref, vec};
 
+use js_int::UInt;
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -41,6 +42,20 @@ impl PollResponseEventContent {
             relates_to: Reference::new(poll_start_id),
         }
     }
+
+    /// Creates a new `PollResponseEventContent` that responds to the given poll start event ID,
+    /// with a single selected answer.
+    pub fn single(answer_id: String, poll_start_id: OwnedEventId) -> Self {
+        Self::new(vec![answer_id].into(), poll_start_id)
+    }
+
+    /// Creates a new `PollResponseEventContent` that responds to the given poll start event ID,
+    /// without any selected answer.
+    ///
+    /// This can be used to abstain from voting, or to remove a previous response.
+    pub fn empty(poll_start_id: OwnedEventId) -> Self {
+        Self::new(Vec::new().into(), poll_start_id)
+    }
 }
 
 /// A block for selections content.
@@ -53,6 +68,39 @@ impl SelectionsContentBlock {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Whether the given answer ID is one of the selections.
+    ///
+    /// This doesn't take `max_selections` into account, use [`Self::validate()`] to get the
+    /// selections that should be counted.
+    pub fn contains_answer(&self, id: &str) -> bool {
+        self.0.iter().any(|selection| selection == id)
+    }
+
+    /// Get the selections that should be taken into account, given the `max_selections` of the
+    /// poll.
+    ///
+    /// According to the spec, only the first `max_selections` selections are valid, the following
+    /// ones are ignored.
+    pub fn validate(&self, max_selections: UInt) -> &[String] {
+        let max_selections = usize::try_from(max_selections).unwrap_or(usize::MAX);
+        &self.0[..self.0.len().min(max_selections)]
+    }
+
+    /// Get the selections that match one of the given answer IDs of the poll.
+    ///
+    /// According to the spec, selections that don't match an answer of the poll are ignored. This
+    /// doesn't take `max_selections` into account, use [`Self::validate()`] for that.
+    pub fn validate_against(&self, valid_ids: &BTreeSet<String>) -> Vec<&str> {
+        self.0.iter().filter(|id| valid_ids.contains(*id)).map(String::as_str).collect()
+    }
+
+    /// Get the first selection, if any.
+    ///
+    /// This is the only valid selection for polls with the default `max_selections` of `1`.
+    pub fn first_valid(&self) -> Option<&str> {
+        self.0.first().map(String::as_str)
+    }
 }
 
 impl From<Vec<String>> for SelectionsContentBlock {
@@ -76,6 +124,18 @@ impl FromIterator<String> for SelectionsContentBlock {
     }
 }
 
+impl<'a> FromIterator<&'a str> for SelectionsContentBlock {
+    fn from_iter<T: IntoIterator<Item = &'a str>>(iter: T) -> Self {
+        Self(iter.into_iter().map(ToOwned::to_owned).collect())
+    }
+}
+
+impl Extend<String> for SelectionsContentBlock {
+    fn extend<T: IntoIterator<Item = String>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
 impl Deref for SelectionsContentBlock {
     type Target = [String];
 
@@ -9,8 +9,11 @@ use serde::{Deserialize, Serialize};
 
 use super::AnyMessageLikeEvent;
 use crate::{
-    serde::{Raw, StringEnum},
-    OwnedEventId, PrivOwnedStr,
+    serde::{
+        base64::{Base64Config, Standard, UrlSafe},
+        Base64, Raw, StringEnum,
+    },
+    EventId, IdParseError, OwnedEventId, PrivOwnedStr, RoomVersionId,
 };
 
 mod rel_serde;
@@ -163,6 +166,35 @@ impl Reference {
     pub fn new(event_id: OwnedEventId) -> Self {
         Self { event_id }
     }
+
+    /// Creates a new `Reference` with the given event ID, checking that it uses the event ID
+    /// format of the given room version.
+    ///
+    /// Room versions 1 and 2 use event IDs with a server name, room version 3 uses the standard
+    /// base64 encoding of the event's reference hash, and later room versions use the URL-safe
+    /// base64 encoding of the reference hash. Returns [`IdParseError::InvalidCharacters`] if the
+    /// event ID doesn't match the format of the room version.
+    pub fn try_new(
+        event_id: OwnedEventId,
+        room_version: &RoomVersionId,
+    ) -> Result<Self, IdParseError> {
+        let valid = match room_version {
+            RoomVersionId::V1 | RoomVersionId::V2 => event_id.server_name().is_some(),
+            RoomVersionId::V3 => is_reference_hash::<Standard>(&event_id),
+            _ => is_reference_hash::<UrlSafe>(&event_id),
+        };
+
+        if !valid {
+            return Err(IdParseError::InvalidCharacters);
+        }
+
+        Ok(Self { event_id })
+    }
+}
+
+/// Whether the given event ID is made of the base64-encoded SHA-256 reference hash of an event.
+fn is_reference_hash<C: Base64Config>(event_id: &EventId) -> bool {
+    event_id.server_name().is_none() && Base64::<C>::parse_exact(event_id.localpart(), 32).is_ok()
 }
 
 /// A bundled reference.
@@ -20,9 +20,13 @@ use crate::{
 };
 
 mod change;
+mod transition;
 
 use self::change::membership_change;
-pub use self::change::{Change, MembershipChange, MembershipDetails};
+pub use self::{
+    change::{Change, MembershipChange, MembershipDetails},
+    transition::{validate_transition, MembershipError},
+};
 
 /// The content of an `m.room.member` event.
 ///
@@ -168,7 +172,9 @@ impl RedactContent for RoomMemberEventContent {
         RedactedRoomMemberEventContent {
             membership: self.membership,
             join_authorized_via_users_server: match _version {
-                RoomVersionId::V9 | RoomVersionId::V10 => self.join_authorized_via_users_server,
+                RoomVersionId::V9 | RoomVersionId::V10 | RoomVersionId::V11 => {
+                    self.join_authorized_via_users_server
+                }
                 _ => None,
             },
         }
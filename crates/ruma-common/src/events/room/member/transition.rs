@@ -0,0 +1,302 @@
+use super::MembershipState;
+use crate::{
+    events::room::{join_rules::JoinRule, power_levels::RoomPowerLevels},
+    UserId,
+};
+
+/// Check whether a membership transition is allowed by the authorization rules for
+/// [`m.room.member`] events.
+///
+/// # Parameters
+///
+/// * `prev`: The current membership of the target user, `Leave` if they don't have any.
+/// * `next`: The membership of the target user after the transition.
+/// * `sender`: The user sending the `m.room.member` event.
+/// * `sender_membership`: The current membership of the sender.
+/// * `target`: The user whose membership changes, i.e. the state key of the event.
+/// * `power_levels`: The current power levels of the room.
+/// * `join_rule`: The current join rule of the room.
+///
+/// This doesn't handle the first join of the room creator, invites from a third-party invite, or
+/// joins to a room with a restricted join rule that are authorized by a resident server. The
+/// latter return [`MembershipError::JoinNotAuthorized`] if the target was not already invited.
+///
+/// [`m.room.member`]: https://spec.matrix.org/latest/rooms/v10/#authorization-rules
+pub fn validate_transition(
+    prev: &MembershipState,
+    next: &MembershipState,
+    sender: &UserId,
+    sender_membership: &MembershipState,
+    target: &UserId,
+    power_levels: &RoomPowerLevels,
+    join_rule: &JoinRule,
+) -> Result<(), MembershipError> {
+    use MembershipState as St;
+
+    let sender_pl = power_levels.for_user(sender);
+    let target_pl = power_levels.for_user(target);
+
+    match next {
+        St::Join => {
+            if sender != target {
+                return Err(MembershipError::NotSelf);
+            }
+            if *prev == St::Ban {
+                return Err(MembershipError::TargetBanned);
+            }
+
+            let already_invited = matches!(prev, St::Invite | St::Join);
+            match join_rule {
+                JoinRule::Public => Ok(()),
+                JoinRule::Invite | JoinRule::Knock if already_invited => Ok(()),
+                JoinRule::Restricted(_) | JoinRule::KnockRestricted(_) if already_invited => Ok(()),
+                JoinRule::Restricted(_) | JoinRule::KnockRestricted(_) => {
+                    Err(MembershipError::JoinNotAuthorized)
+                }
+                _ => Err(MembershipError::JoinRuleForbids),
+            }
+        }
+        St::Invite => {
+            if *sender_membership != St::Join {
+                return Err(MembershipError::SenderNotJoined);
+            }
+
+            match prev {
+                St::Ban => Err(MembershipError::TargetBanned),
+                St::Join => Err(MembershipError::InvalidTransition),
+                _ if sender_pl >= power_levels.invite => Ok(()),
+                _ => Err(MembershipError::InsufficientPowerLevel),
+            }
+        }
+        St::Leave if sender == target => match prev {
+            St::Invite | St::Join | St::Knock => Ok(()),
+            _ => Err(MembershipError::InvalidTransition),
+        },
+        St::Leave => {
+            if *sender_membership != St::Join {
+                return Err(MembershipError::SenderNotJoined);
+            }
+
+            if *prev == St::Ban && sender_pl < power_levels.ban {
+                Err(MembershipError::InsufficientPowerLevel)
+            } else if sender_pl >= power_levels.kick && target_pl < sender_pl {
+                Ok(())
+            } else {
+                Err(MembershipError::InsufficientPowerLevel)
+            }
+        }
+        St::Ban => {
+            if *sender_membership != St::Join {
+                return Err(MembershipError::SenderNotJoined);
+            }
+
+            if sender_pl >= power_levels.ban && target_pl < sender_pl {
+                Ok(())
+            } else {
+                Err(MembershipError::InsufficientPowerLevel)
+            }
+        }
+        St::Knock => {
+            if !matches!(join_rule, JoinRule::Knock | JoinRule::KnockRestricted(_)) {
+                return Err(MembershipError::JoinRuleForbids);
+            }
+            if sender != target {
+                return Err(MembershipError::NotSelf);
+            }
+
+            match prev {
+                St::Ban => Err(MembershipError::TargetBanned),
+                St::Invite | St::Join => Err(MembershipError::InvalidTransition),
+                _ => Ok(()),
+            }
+        }
+        _ => Err(MembershipError::UnknownMembership),
+    }
+}
+
+/// An error returned by [`validate_transition()`] when a membership transition is not allowed.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum MembershipError {
+    /// The sender must be the target user to join or knock.
+    #[error("only the target user can change their membership to this state")]
+    NotSelf,
+
+    /// The sender must be in the room to invite, kick or ban.
+    #[error("the sender is not in the room")]
+    SenderNotJoined,
+
+    /// The target user is banned from the room.
+    #[error("the target user is banned")]
+    TargetBanned,
+
+    /// The join rule of the room doesn't allow this transition.
+    #[error("the join rule of the room does not allow this membership")]
+    JoinRuleForbids,
+
+    /// The join to a room with a restricted join rule must be authorized by a resident server.
+    #[error("the join must be authorized by a server in the room")]
+    JoinNotAuthorized,
+
+    /// The sender's power level is too low, or not higher than the target's.
+    #[error("the sender's power level is too low")]
+    InsufficientPowerLevel,
+
+    /// The target user can't go from their current membership to the new one.
+    #[error("invalid membership transition")]
+    InvalidTransition,
+
+    /// The new membership is not known.
+    #[error("unknown membership")]
+    UnknownMembership,
+}
+
+#[cfg(test)]
+mod tests {
+    use assign::assign;
+    use js_int::int;
+    use maplit::btreemap;
+
+    use super::{validate_transition, MembershipError};
+    use crate::{
+        events::room::{
+            join_rules::JoinRule,
+            member::MembershipState,
+            power_levels::{RoomPowerLevels, RoomPowerLevelsEventContent},
+        },
+        user_id, UserId,
+    };
+
+    #[test]
+    fn unauthorized_kick() {
+        let moderator = user_id!("@moderator:example.com");
+        let member = user_id!("@member:example.com");
+        let admin = user_id!("@admin:example.com");
+        let power_levels: RoomPowerLevels = assign!(RoomPowerLevelsEventContent::new(), {
+            users: btreemap! {
+                moderator.to_owned() => int!(50),
+                admin.to_owned() => int!(100),
+            },
+        })
+        .into();
+
+        // A user without the kick power level.
+        assert_eq!(
+            validate_transition(
+                &MembershipState::Join,
+                &MembershipState::Leave,
+                member,
+                &MembershipState::Join,
+                moderator,
+                &power_levels,
+                &JoinRule::Public,
+            ),
+            Err(MembershipError::InsufficientPowerLevel)
+        );
+
+        // A user with the kick power level, but not higher than the target.
+        assert_eq!(
+            validate_transition(
+                &MembershipState::Join,
+                &MembershipState::Leave,
+                moderator,
+                &MembershipState::Join,
+                admin,
+                &power_levels,
+                &JoinRule::Public,
+            ),
+            Err(MembershipError::InsufficientPowerLevel)
+        );
+
+        // A user who is not in the room.
+        assert_eq!(
+            validate_transition(
+                &MembershipState::Join,
+                &MembershipState::Leave,
+                admin,
+                &MembershipState::Leave,
+                member,
+                &power_levels,
+                &JoinRule::Public,
+            ),
+            Err(MembershipError::SenderNotJoined)
+        );
+
+        validate_transition(
+            &MembershipState::Join,
+            &MembershipState::Leave,
+            moderator,
+            &MembershipState::Join,
+            member,
+            &power_levels,
+            &JoinRule::Public,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn self_leave() {
+        let member = user_id!("@member:example.com");
+        let power_levels: RoomPowerLevels = RoomPowerLevelsEventContent::new().into();
+
+        for prev in [MembershipState::Join, MembershipState::Invite, MembershipState::Knock] {
+            validate_transition(
+                &prev,
+                &MembershipState::Leave,
+                member,
+                &prev,
+                member,
+                &power_levels,
+                &JoinRule::Invite,
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            validate_transition(
+                &MembershipState::Ban,
+                &MembershipState::Leave,
+                member,
+                &MembershipState::Ban,
+                member,
+                &power_levels,
+                &JoinRule::Invite,
+            ),
+            Err(MembershipError::InvalidTransition)
+        );
+    }
+
+    #[test]
+    fn join() {
+        let member = user_id!("@member:example.com");
+        let other = user_id!("@other:example.com");
+        let power_levels: RoomPowerLevels = RoomPowerLevelsEventContent::new().into();
+
+        let join = |prev: MembershipState, sender: &UserId, join_rule: JoinRule| {
+            validate_transition(
+                &prev,
+                &MembershipState::Join,
+                sender,
+                &prev,
+                member,
+                &power_levels,
+                &join_rule,
+            )
+        };
+
+        join(MembershipState::Leave, member, JoinRule::Public).unwrap();
+        join(MembershipState::Invite, member, JoinRule::Invite).unwrap();
+        assert_eq!(
+            join(MembershipState::Leave, member, JoinRule::Invite),
+            Err(MembershipError::JoinRuleForbids)
+        );
+        assert_eq!(
+            join(MembershipState::Ban, member, JoinRule::Public),
+            Err(MembershipError::TargetBanned)
+        );
+        assert_eq!(
+            join(MembershipState::Invite, other, JoinRule::Public),
+            Err(MembershipError::NotSelf)
+        );
+    }
+}
@@ -130,6 +130,64 @@ impl RoomPowerLevelsEventContent {
             notifications: NotificationPowerLevels::default(),
         }
     }
+
+    /// Get the power level of a specific user.
+    pub fn for_user(&self, user_id: &UserId) -> Int {
+        self.borrowed().for_user(user_id)
+    }
+
+    /// Whether the given user can send an event of the given type based on the power levels.
+    ///
+    /// `state` indicates whether the event is a state event, in which case `state_default` applies
+    /// if the event type has no specific power level, instead of `events_default`.
+    pub fn user_can_send(&self, user_id: &UserId, event_type: &str, state: bool) -> bool {
+        self.borrowed().user_can_send(user_id, event_type, state)
+    }
+
+    /// Whether the given user can do the given action based on the power levels.
+    pub fn user_can_do(&self, user_id: &UserId, action: PowerLevelAction) -> bool {
+        self.borrowed().user_can_do(user_id, action)
+    }
+
+    fn borrowed(&self) -> PowerLevelsRef<'_> {
+        PowerLevelsRef {
+            ban: self.ban,
+            events: &self.events,
+            events_default: self.events_default,
+            invite: self.invite,
+            kick: self.kick,
+            redact: self.redact,
+            state_default: self.state_default,
+            users: &self.users,
+            users_default: self.users_default,
+            notifications: &self.notifications,
+        }
+    }
+
+    /// Compute the changes between these power levels and the given ones.
+    ///
+    /// `self` is considered to be the previous content and `other` the new content, so a user that
+    /// only appears in `other` is reported as [`PowerLevelChange::Added`].
+    ///
+    /// Only the entries of `users`, `events` and `notifications` are compared.
+    pub fn diff(&self, other: &Self) -> PowerLevelsDiff {
+        let mut notifications = BTreeMap::new();
+        if self.notifications.room != other.notifications.room {
+            notifications.insert(
+                "room".to_owned(),
+                PowerLevelChange::Changed {
+                    old: self.notifications.room,
+                    new: other.notifications.room,
+                },
+            );
+        }
+
+        PowerLevelsDiff {
+            users: diff_maps(&self.users, &other.users),
+            events: diff_maps(&self.events, &other.events),
+            notifications,
+        }
+    }
 }
 
 impl Default for RoomPowerLevelsEventContent {
@@ -221,37 +279,34 @@ pub struct RoomPowerLevels {
 impl RoomPowerLevels {
     /// Get the power level of a specific user.
     pub fn for_user(&self, user_id: &UserId) -> Int {
-        self.users.get(user_id).map_or(self.users_default, |pl| *pl)
+        self.borrowed().for_user(user_id)
+    }
+
+    /// Whether the given user can send an event of the given type based on the power levels.
+    ///
+    /// `state` indicates whether the event is a state event, in which case `state_default` applies
+    /// if the event type has no specific power level, instead of `events_default`.
+    pub fn user_can_send(&self, user_id: &UserId, event_type: &str, state: bool) -> bool {
+        self.borrowed().user_can_send(user_id, event_type, state)
     }
 
     /// Whether the given user can do the given action based on the power levels.
     pub fn user_can_do(&self, user_id: &UserId, action: PowerLevelAction) -> bool {
-        let user_pl = self.for_user(user_id);
+        self.borrowed().user_can_do(user_id, action)
+    }
 
-        match action {
-            PowerLevelAction::Ban => user_pl >= self.ban,
-            PowerLevelAction::Invite => user_pl >= self.invite,
-            PowerLevelAction::Kick => user_pl >= self.kick,
-            PowerLevelAction::Redact => user_pl >= self.redact,
-            PowerLevelAction::SendMessage(message_type) => {
-                user_pl
-                    >= self
-                        .events
-                        .get(&message_type.into())
-                        .map(ToOwned::to_owned)
-                        .unwrap_or(self.events_default)
-            }
-            PowerLevelAction::SendState(state_type) => {
-                user_pl
-                    >= self
-                        .events
-                        .get(&state_type.into())
-                        .map(ToOwned::to_owned)
-                        .unwrap_or(self.state_default)
-            }
-            PowerLevelAction::TriggerNotification(notification_type) => match notification_type {
-                NotificationPowerLevelType::Room => user_pl >= self.notifications.room,
-            },
+    fn borrowed(&self) -> PowerLevelsRef<'_> {
+        PowerLevelsRef {
+            ban: self.ban,
+            events: &self.events,
+            events_default: self.events_default,
+            invite: self.invite,
+            kick: self.kick,
+            redact: self.redact,
+            state_default: self.state_default,
+            users: &self.users,
+            users_default: self.users_default,
+            notifications: &self.notifications,
         }
     }
 
@@ -312,6 +367,125 @@ impl From<RoomPowerLevels> for RoomPowerLevelsEventContent {
     }
 }
 
+/// A borrowed view of power levels, shared by the permission checks of
+/// [`RoomPowerLevelsEventContent`] and [`RoomPowerLevels`].
+struct PowerLevelsRef<'a> {
+    ban: Int,
+    events: &'a BTreeMap<TimelineEventType, Int>,
+    events_default: Int,
+    invite: Int,
+    kick: Int,
+    redact: Int,
+    state_default: Int,
+    users: &'a BTreeMap<OwnedUserId, Int>,
+    users_default: Int,
+    notifications: &'a NotificationPowerLevels,
+}
+
+impl PowerLevelsRef<'_> {
+    fn for_user(&self, user_id: &UserId) -> Int {
+        self.users.get(user_id).map_or(self.users_default, |pl| *pl)
+    }
+
+    /// Get the power level required to send the given event type, falling back to the given
+    /// default if the event type has none.
+    fn for_event(&self, event_type: &TimelineEventType, default: Int) -> Int {
+        self.events.get(event_type).map_or(default, |pl| *pl)
+    }
+
+    fn user_can_send(&self, user_id: &UserId, event_type: &str, state: bool) -> bool {
+        let default = if state { self.state_default } else { self.events_default };
+        self.for_user(user_id) >= self.for_event(&event_type.into(), default)
+    }
+
+    fn user_can_do(&self, user_id: &UserId, action: PowerLevelAction) -> bool {
+        let user_pl = self.for_user(user_id);
+
+        match action {
+            PowerLevelAction::Ban => user_pl >= self.ban,
+            PowerLevelAction::Invite => user_pl >= self.invite,
+            PowerLevelAction::Kick => user_pl >= self.kick,
+            PowerLevelAction::Redact => user_pl >= self.redact,
+            PowerLevelAction::SendMessage(message_type) => {
+                user_pl >= self.for_event(&message_type.into(), self.events_default)
+            }
+            PowerLevelAction::SendState(state_type) => {
+                user_pl >= self.for_event(&state_type.into(), self.state_default)
+            }
+            PowerLevelAction::TriggerNotification(notification_type) => match notification_type {
+                NotificationPowerLevelType::Room => user_pl >= self.notifications.room,
+            },
+        }
+    }
+}
+
+/// The changes between two [`RoomPowerLevelsEventContent`]s.
+///
+/// This type is returned by [`RoomPowerLevelsEventContent::diff()`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub struct PowerLevelsDiff {
+    /// The changes of the power levels of specific users.
+    pub users: BTreeMap<OwnedUserId, PowerLevelChange>,
+
+    /// The changes of the power levels required to send specific event types.
+    pub events: BTreeMap<TimelineEventType, PowerLevelChange>,
+
+    /// The changes of the power levels required to trigger specific notification types.
+    ///
+    /// The keys are the same as the ones accepted by [`NotificationPowerLevels::get()`].
+    pub notifications: BTreeMap<String, PowerLevelChange>,
+}
+
+impl PowerLevelsDiff {
+    /// Whether there are no changes.
+    pub fn is_empty(&self) -> bool {
+        self.users.is_empty() && self.events.is_empty() && self.notifications.is_empty()
+    }
+}
+
+/// The change of a single power level entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub enum PowerLevelChange {
+    /// The entry was added with the given power level.
+    Added(Int),
+
+    /// The entry with the given power level was removed.
+    Removed(Int),
+
+    /// The power level of the entry was changed.
+    Changed {
+        /// The previous power level.
+        old: Int,
+
+        /// The new power level.
+        new: Int,
+    },
+}
+
+fn diff_maps<K: Clone + Ord>(
+    old: &BTreeMap<K, Int>,
+    new: &BTreeMap<K, Int>,
+) -> BTreeMap<K, PowerLevelChange> {
+    let removed_or_changed = old.iter().filter_map(|(key, &old_level)| {
+        let change = match new.get(key) {
+            None => PowerLevelChange::Removed(old_level),
+            Some(&new_level) if new_level != old_level => {
+                PowerLevelChange::Changed { old: old_level, new: new_level }
+            }
+            Some(_) => return None,
+        };
+        Some((key.clone(), change))
+    });
+    let added = new
+        .iter()
+        .filter(|(key, _)| !old.contains_key(*key))
+        .map(|(key, &new_level)| (key.clone(), PowerLevelChange::Added(new_level)));
+
+    removed_or_changed.chain(added).collect()
+}
+
 /// The actions that can be limited by power levels.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
@@ -355,8 +529,14 @@ mod tests {
     use maplit::btreemap;
     use serde_json::{json, to_value as to_json_value};
 
-    use super::{default_power_level, NotificationPowerLevels, RoomPowerLevelsEventContent};
-    use crate::user_id;
+    use super::{
+        default_power_level, NotificationPowerLevelType, NotificationPowerLevels, PowerLevelAction,
+        PowerLevelChange, RoomPowerLevelsEventContent,
+    };
+    use crate::{
+        events::{MessageLikeEventType, StateEventType, TimelineEventType},
+        user_id,
+    };
 
     #[test]
     fn serialization_with_optional_fields_as_none() {
@@ -423,4 +603,105 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn diff() {
+        let alice = user_id!("@alice:example.com");
+        let bob = user_id!("@bob:example.com");
+        let carl = user_id!("@carl:example.com");
+
+        let old = assign!(RoomPowerLevelsEventContent::new(), {
+            events: btreemap! {
+                "m.room.name".into() => int!(50),
+                "m.room.topic".into() => int!(50),
+            },
+            users: btreemap! {
+                alice.to_owned() => int!(100),
+                bob.to_owned() => int!(0),
+                carl.to_owned() => int!(50),
+            },
+        });
+        let new = assign!(RoomPowerLevelsEventContent::new(), {
+            events: btreemap! {
+                "m.room.name".into() => int!(0),
+                "m.room.topic".into() => int!(50),
+            },
+            users: btreemap! {
+                alice.to_owned() => int!(100),
+                bob.to_owned() => int!(50),
+            },
+            notifications: assign!(NotificationPowerLevels::new(), { room: int!(100) }),
+        });
+
+        let diff = old.diff(&new);
+        assert_eq!(
+            diff.users,
+            btreemap! {
+                bob.to_owned() => PowerLevelChange::Changed { old: int!(0), new: int!(50) },
+                carl.to_owned() => PowerLevelChange::Removed(int!(50)),
+            }
+        );
+        assert_eq!(
+            diff.events,
+            btreemap! {
+                TimelineEventType::RoomName => PowerLevelChange::Changed {
+                    old: int!(50),
+                    new: int!(0),
+                },
+            }
+        );
+        assert_eq!(
+            diff.notifications,
+            btreemap! {
+                "room".to_owned() => PowerLevelChange::Changed { old: int!(50), new: int!(100) },
+            }
+        );
+
+        let reverse_diff = new.diff(&old);
+        assert_eq!(reverse_diff.users[carl], PowerLevelChange::Added(int!(50)));
+
+        assert!(old.diff(&old).is_empty());
+    }
+
+    #[test]
+    fn user_can_send_and_do() {
+        let admin = user_id!("@admin:example.com");
+        let muted = user_id!("@muted:example.com");
+        let other = user_id!("@other:example.com");
+
+        let power_levels = assign!(RoomPowerLevelsEventContent::new(), {
+            events: btreemap! {
+                "m.room.message".into() => int!(10),
+                "m.room.topic".into() => int!(0),
+            },
+            users: btreemap! {
+                admin.to_owned() => int!(100),
+                muted.to_owned() => int!(-1),
+            },
+        });
+
+        // Users without a specific power level use `users_default`, that is 0.
+        assert_eq!(power_levels.for_user(other), int!(0));
+        assert!(!power_levels.user_can_send(other, "m.room.message", false));
+        assert!(power_levels.user_can_send(other, "m.reaction", false));
+        assert!(power_levels.user_can_send(other, "m.room.topic", true));
+        assert!(!power_levels.user_can_send(other, "m.room.name", true));
+        assert!(!power_levels.user_can_do(other, PowerLevelAction::Kick));
+        assert!(power_levels.user_can_do(other, PowerLevelAction::Invite));
+        assert!(
+            power_levels.user_can_do(other, PowerLevelAction::SendState(StateEventType::RoomTopic))
+        );
+        assert!(!power_levels
+            .user_can_do(other, PowerLevelAction::SendMessage(MessageLikeEventType::RoomMessage)));
+
+        // Users with an explicit power level.
+        assert!(!power_levels.user_can_send(muted, "m.reaction", false));
+        assert!(!power_levels.user_can_do(muted, PowerLevelAction::Invite));
+        assert!(power_levels.user_can_send(admin, "m.room.name", true));
+        assert!(power_levels.user_can_do(admin, PowerLevelAction::Ban));
+        assert!(power_levels.user_can_do(
+            admin,
+            PowerLevelAction::TriggerNotification(NotificationPowerLevelType::Room)
+        ));
+    }
 }
@@ -12,7 +12,8 @@ use crate::{
         RedactionDeHelper,
     },
     serde::from_raw_json_value,
-    EventId, MilliSecondsSinceUnixEpoch, OwnedEventId, OwnedRoomId, OwnedUserId, RoomId, UserId,
+    EventId, MilliSecondsSinceUnixEpoch, OwnedEventId, OwnedRoomId, OwnedUserId, RoomId,
+    RoomVersionId, UserId,
 };
 
 /// A possibly-redacted redaction event.
@@ -45,6 +46,10 @@ pub struct OriginalRoomRedactionEvent {
     pub content: RoomRedactionEventContent,
 
     /// The ID of the event that was redacted.
+    ///
+    /// Starting from room version 11, this field is also part of the content, and servers keep
+    /// it at the top level of the event for backwards compatibility. Use
+    /// [`redacts`][Self::redacts] to get it according to the rules of a room version.
     pub redacts: OwnedEventId,
 
     /// The globally unique event identifier for the user who sent the event.
@@ -94,6 +99,10 @@ pub struct OriginalSyncRoomRedactionEvent {
     pub content: RoomRedactionEventContent,
 
     /// The ID of the event that was redacted.
+    ///
+    /// Starting from room version 11, this field is also part of the content, and servers keep
+    /// it at the top level of the event for backwards compatibility. Use
+    /// [`redacts`][Self::redacts] to get it according to the rules of a room version.
     pub redacts: OwnedEventId,
 
     /// The globally unique event identifier for the user who sent the event.
@@ -134,6 +143,13 @@ pub struct RedactedSyncRoomRedactionEvent {
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
 #[ruma_event(type = "m.room.redaction", kind = MessageLike)]
 pub struct RoomRedactionEventContent {
+    /// The ID of the event that was redacted.
+    ///
+    /// This field is required starting from room version 11, in previous room versions it is
+    /// at the top level of the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redacts: Option<OwnedEventId>,
+
     /// The reason for the redaction, if any.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
@@ -141,13 +157,67 @@ pub struct RoomRedactionEventContent {
 
 impl RoomRedactionEventContent {
     /// Creates an empty `RoomRedactionEventContent`.
+    ///
+    /// This is the form of the content used in room versions prior to 11.
     pub fn new() -> Self {
         Self::default()
     }
 
     /// Creates a new `RoomRedactionEventContent` with the given reason.
     pub fn with_reason(reason: String) -> Self {
-        Self { reason: Some(reason) }
+        Self { reason: Some(reason), ..Self::default() }
+    }
+
+    /// Creates a new `RoomRedactionEventContent` redacting the given event, for room version 11
+    /// and later.
+    pub fn new_v11(redacts: OwnedEventId) -> Self {
+        Self { redacts: Some(redacts), ..Self::default() }
+    }
+
+    /// Creates a new `RoomRedactionEventContent` redacting the given event, using the form of the
+    /// content of the given room version.
+    ///
+    /// In room versions prior to 11, `redacts` is not part of the content and is only sent at the
+    /// top level of the event.
+    pub fn for_room_version(redacts: OwnedEventId, room_version: &RoomVersionId) -> Self {
+        if room_version.redacts_in_content() {
+            Self::new_v11(redacts)
+        } else {
+            Self::new()
+        }
+    }
+}
+
+impl OriginalRoomRedactionEvent {
+    /// Get the ID of the event that was redacted, according to the rules of the given room
+    /// version.
+    ///
+    /// Starting from room version 11, the `redacts` field of the content takes precedence over the
+    /// one at the top level of the event.
+    pub fn redacts(&self, room_version: &RoomVersionId) -> &EventId {
+        redacts(&self.content, &self.redacts, room_version)
+    }
+}
+
+impl OriginalSyncRoomRedactionEvent {
+    /// Get the ID of the event that was redacted, according to the rules of the given room
+    /// version.
+    ///
+    /// Starting from room version 11, the `redacts` field of the content takes precedence over the
+    /// one at the top level of the event.
+    pub fn redacts(&self, room_version: &RoomVersionId) -> &EventId {
+        redacts(&self.content, &self.redacts, room_version)
+    }
+}
+
+fn redacts<'a>(
+    content: &'a RoomRedactionEventContent,
+    top_level: &'a EventId,
+    room_version: &RoomVersionId,
+) -> &'a EventId {
+    match &content.redacts {
+        Some(redacts) if room_version.redacts_in_content() => redacts,
+        _ => top_level,
     }
 }
 
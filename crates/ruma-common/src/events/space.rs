@@ -4,3 +4,165 @@
 
 pub mod child;
 pub mod parent;
+
+use self::{child::SpaceChildEventContent, parent::SpaceParentEventContent};
+use super::OriginalStateEvent;
+use crate::{dedup_valid_servers, OwnedServerName};
+
+/// The largest number of servers allowed in `via` by [`SpaceChildEventContent::with_via`] and
+/// [`SpaceParentEventContent::with_via`].
+pub const MAX_VIA_SERVERS: usize = 50;
+
+/// An error encountered when trying to use an invalid `via` for an `m.space.child` or
+/// `m.space.parent` event.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum SpaceViaError {
+    /// The list of servers is empty.
+    #[error("via is empty")]
+    Empty,
+
+    /// The list contains more than [`MAX_VIA_SERVERS`] servers.
+    #[error("via contains too many servers")]
+    TooMany,
+}
+
+/// Deduplicate the given `via` servers, keeping the first occurrence of each server, and check
+/// that there are between 1 and [`MAX_VIA_SERVERS`] of them.
+fn validate_via(servers: &[OwnedServerName]) -> Result<Vec<OwnedServerName>, SpaceViaError> {
+    let via = dedup_valid_servers(servers);
+
+    if via.is_empty() {
+        Err(SpaceViaError::Empty)
+    } else if via.len() > MAX_VIA_SERVERS {
+        Err(SpaceViaError::TooMany)
+    } else {
+        Ok(via)
+    }
+}
+
+/// Whether an `m.space.child` and an `m.space.parent` event agree on the relationship between a
+/// space and a room.
+///
+/// The relationship is reciprocal if `child` is sent in the space with the room ID as state key,
+/// `parent` is sent in that room with the space ID as state key, and both have a non-empty `via`,
+/// since an event with an empty or missing `via` is treated as if it was absent.
+pub fn is_reciprocal(
+    child: &OriginalStateEvent<SpaceChildEventContent>,
+    parent: &OriginalStateEvent<SpaceParentEventContent>,
+) -> bool {
+    let has_via = |via: &Option<Vec<_>>| matches!(via, Some(via) if !via.is_empty());
+
+    child.room_id == parent.state_key
+        && child.state_key == parent.room_id
+        && has_via(&child.content.via)
+        && has_via(&parent.content.via)
+}
+
+/// Filter the given `m.space.child` event contents to only keep the suggested children.
+///
+/// A missing `suggested` field is deserialized as `false`, so those children are filtered out.
+pub fn suggested_children<'a>(
+    children: impl Iterator<Item = &'a SpaceChildEventContent>,
+) -> impl Iterator<Item = &'a SpaceChildEventContent> {
+    children.filter(|child| child.suggested)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value as from_json_value, json, Value as JsonValue};
+
+    use super::{
+        child::SpaceChildEventContent, is_reciprocal, parent::SpaceParentEventContent,
+        suggested_children,
+    };
+    use crate::events::OriginalStateEvent;
+
+    fn child_event(
+        space_id: &str,
+        room_id: &str,
+        via: JsonValue,
+    ) -> OriginalStateEvent<SpaceChildEventContent> {
+        from_json_value(json!({
+            "content": { "via": via },
+            "event_id": "$child:example.com",
+            "origin_server_ts": 1,
+            "room_id": space_id,
+            "sender": "@alice:example.com",
+            "state_key": room_id,
+            "type": "m.space.child",
+        }))
+        .unwrap()
+    }
+
+    fn parent_event(
+        room_id: &str,
+        space_id: &str,
+        via: JsonValue,
+    ) -> OriginalStateEvent<SpaceParentEventContent> {
+        from_json_value(json!({
+            "content": { "via": via },
+            "event_id": "$parent:example.com",
+            "origin_server_ts": 1,
+            "room_id": room_id,
+            "sender": "@alice:example.com",
+            "state_key": space_id,
+            "type": "m.space.parent",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn reciprocal() {
+        let child = child_event("!space:example.com", "!room:example.org", json!(["example.org"]));
+        let parent =
+            parent_event("!room:example.org", "!space:example.com", json!(["example.com"]));
+
+        assert!(is_reciprocal(&child, &parent));
+    }
+
+    #[test]
+    fn unrelated() {
+        let child = child_event("!space:example.com", "!room:example.org", json!(["example.org"]));
+
+        let other_space =
+            parent_event("!room:example.org", "!other:example.com", json!(["example.com"]));
+        assert!(!is_reciprocal(&child, &other_space));
+
+        let other_room =
+            parent_event("!other:example.org", "!space:example.com", json!(["example.com"]));
+        assert!(!is_reciprocal(&child, &other_room));
+    }
+
+    #[test]
+    fn one_sided() {
+        let child = child_event("!space:example.com", "!room:example.org", json!(["example.org"]));
+        let parent =
+            parent_event("!room:example.org", "!space:example.com", json!(["example.com"]));
+
+        let empty_child = child_event("!space:example.com", "!room:example.org", json!([]));
+        assert!(!is_reciprocal(&empty_child, &parent));
+
+        let empty_parent = parent_event("!room:example.org", "!space:example.com", json!([]));
+        assert!(!is_reciprocal(&child, &empty_parent));
+
+        let missing_via_parent =
+            parent_event("!room:example.org", "!space:example.com", json!(null));
+        assert!(!is_reciprocal(&child, &missing_via_parent));
+    }
+
+    #[test]
+    fn only_suggested_children() {
+        let children: Vec<SpaceChildEventContent> = from_json_value(json!([
+            { "via": ["example.com"], "order": "a", "suggested": true },
+            { "via": ["example.com"], "order": "b", "suggested": false },
+            { "via": ["example.com"], "order": "c" },
+            { "via": ["example.com"], "order": "d", "suggested": true },
+        ]))
+        .unwrap();
+
+        let orders: Vec<_> =
+            suggested_children(children.iter()).map(|child| child.order.as_deref()).collect();
+        assert_eq!(orders, [Some("a"), Some("d")]);
+    }
+}
@@ -2,9 +2,13 @@
 //!
 //! [`m.space.child`]: https://spec.matrix.org/latest/client-server-api/#mspacechild
 
+use std::cmp::Ordering;
+
 use ruma_macros::{Event, EventContent};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value as JsonValue;
 
+use super::{validate_via, SpaceViaError};
 use crate::{MilliSecondsSinceUnixEpoch, OwnedRoomId, OwnedServerName, OwnedUserId};
 
 /// The content of an `m.space.child` event.
@@ -40,15 +44,108 @@ pub struct SpaceChildEventContent {
     /// example by showing them eagerly in the room list. A child which is missing the `suggested`
     /// property is treated identically to a child with `"suggested": false`. A suggested child may
     /// be a room or a subspace.
-    #[serde(default, skip_serializing_if = "ruma_common::serde::is_default")]
+    ///
+    /// When deserializing, the strings `"true"` and `"false"` are also accepted, and a malformed
+    /// value is treated like `false`.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_suggested",
+        skip_serializing_if = "ruma_common::serde::is_default"
+    )]
     pub suggested: bool,
 }
 
 impl SpaceChildEventContent {
+    /// The largest number of characters allowed in `order`.
+    pub const MAX_ORDER_LENGTH: usize = 50;
+
     /// Creates a new `ChildEventContent`.
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Creates a new `ChildEventContent` with the given `order`.
+    ///
+    /// Returns an error if `order` is longer than [`Self::MAX_ORDER_LENGTH`] or contains
+    /// characters outside of the ASCII range `\x20` (space) to `\x7E` (`~`).
+    pub fn with_order(order: String) -> Result<Self, SpaceChildOrderError> {
+        validate_order(&order)?;
+        Ok(Self { order: Some(order), ..Self::default() })
+    }
+
+    /// Creates a new `ChildEventContent` with the given `via` servers.
+    ///
+    /// Duplicate servers are removed, keeping the first occurrence of each server. Returns an
+    /// error if `servers` is empty, since an empty `via` means that the child is not part of the
+    /// space, or if it contains more than [`MAX_VIA_SERVERS`](super::MAX_VIA_SERVERS) unique
+    /// servers.
+    pub fn with_via(servers: Vec<OwnedServerName>) -> Result<Self, SpaceViaError> {
+        let via = validate_via(&servers)?;
+        Ok(Self { via: Some(via), ..Self::default() })
+    }
+}
+
+/// An error encountered when trying to use an invalid `order` for a `SpaceChildEventContent`.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum SpaceChildOrderError {
+    /// The value is longer than [`SpaceChildEventContent::MAX_ORDER_LENGTH`].
+    #[error("order is too long")]
+    TooLong,
+
+    /// The value contains characters that are not ASCII printable characters.
+    #[error("order contains invalid characters")]
+    InvalidCharacters,
+}
+
+fn validate_order(order: &str) -> Result<(), SpaceChildOrderError> {
+    if order.len() > SpaceChildEventContent::MAX_ORDER_LENGTH {
+        Err(SpaceChildOrderError::TooLong)
+    } else if !order.bytes().all(|b| matches!(b, b'\x20'..=b'\x7E')) {
+        Err(SpaceChildOrderError::InvalidCharacters)
+    } else {
+        Ok(())
+    }
+}
+
+/// Deserializes `suggested` leniently, accepting booleans and their string forms and ignoring any
+/// other value.
+fn deserialize_suggested<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match JsonValue::deserialize(deserializer)? {
+        JsonValue::Bool(suggested) => suggested,
+        JsonValue::String(s) => s == "true",
+        _ => false,
+    })
+}
+
+/// Sort the children of a space according to the ordering rules of the spec.
+///
+/// Each child is given as a tuple of its room ID, the content of its `m.space.child` event and
+/// the timestamp used as a fallback, i.e. the `origin_server_ts` of the `m.space.child` event.
+///
+/// Children are sorted based on a lexicographic ordering of their `order`. Children without an
+/// `order`, or with an invalid one, come last. Children that can't be ordered this way are sorted
+/// by ascending timestamp, then by ascending room ID.
+pub fn sort_space_children(
+    children: &mut [(OwnedRoomId, SpaceChildEventContent, MilliSecondsSinceUnixEpoch)],
+) {
+    fn valid_order(content: &SpaceChildEventContent) -> Option<&str> {
+        content.order.as_deref().filter(|order| validate_order(order).is_ok())
+    }
+
+    children.sort_by(|(a_id, a_content, a_ts), (b_id, b_content, b_ts)| {
+        let order = match (valid_order(a_content), valid_order(b_content)) {
+            (Some(a), Some(b)) => a.cmp(b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        };
+
+        order.then_with(|| a_ts.cmp(b_ts)).then_with(|| a_id.cmp(b_id))
+    });
 }
 
 /// An `m.space.child` event represented as a Stripped State Event with an added `origin_server_ts`
@@ -74,8 +171,48 @@ mod tests {
     use js_int::uint;
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
-    use super::{HierarchySpaceChildEvent, SpaceChildEventContent};
-    use crate::{server_name, MilliSecondsSinceUnixEpoch};
+    use super::{
+        sort_space_children, HierarchySpaceChildEvent, SpaceChildEventContent,
+        SpaceChildOrderError, SpaceViaError,
+    };
+    use crate::{room_id, server_name, MilliSecondsSinceUnixEpoch};
+
+    #[test]
+    fn space_child_lenient_suggested() {
+        let content = from_json_value::<SpaceChildEventContent>(json!({
+            "via": ["example.com"],
+            "suggested": true,
+        }))
+        .unwrap();
+        assert!(content.suggested);
+
+        let content = from_json_value::<SpaceChildEventContent>(json!({
+            "via": ["example.com"],
+            "suggested": "true",
+        }))
+        .unwrap();
+        assert!(content.suggested);
+
+        let content = from_json_value::<SpaceChildEventContent>(json!({
+            "via": ["example.com"],
+            "suggested": "false",
+        }))
+        .unwrap();
+        assert!(!content.suggested);
+
+        let mut content = from_json_value::<SpaceChildEventContent>(json!({
+            "via": ["example.com"],
+            "suggested": { "garbage": 1 },
+        }))
+        .unwrap();
+        assert!(!content.suggested);
+
+        content.suggested = true;
+        assert_eq!(
+            to_json_value(&content).unwrap(),
+            json!({ "via": ["example.com"], "suggested": true })
+        );
+    }
 
     #[test]
     fn space_child_serialization() {
@@ -102,6 +239,79 @@ mod tests {
         assert_eq!(to_json_value(&content).unwrap(), json);
     }
 
+    #[test]
+    fn space_child_with_order() {
+        let content = SpaceChildEventContent::with_order("a".repeat(50)).unwrap();
+        assert_eq!(content.order.as_deref(), Some("a".repeat(50).as_str()));
+
+        let content = SpaceChildEventContent::with_order(" !azAZ09~".to_owned()).unwrap();
+        assert_eq!(content.order.as_deref(), Some(" !azAZ09~"));
+    }
+
+    #[test]
+    fn space_child_with_invalid_order() {
+        assert_eq!(
+            SpaceChildEventContent::with_order("a".repeat(51)).unwrap_err(),
+            SpaceChildOrderError::TooLong
+        );
+        assert_eq!(
+            SpaceChildEventContent::with_order("a\nb".to_owned()).unwrap_err(),
+            SpaceChildOrderError::InvalidCharacters
+        );
+        assert_eq!(
+            SpaceChildEventContent::with_order("é".to_owned()).unwrap_err(),
+            SpaceChildOrderError::InvalidCharacters
+        );
+    }
+
+    #[test]
+    fn space_child_with_via() {
+        let content = SpaceChildEventContent::with_via(vec![
+            server_name!("example.com").to_owned(),
+            server_name!("example.org").to_owned(),
+            server_name!("example.com").to_owned(),
+        ])
+        .unwrap();
+        assert_eq!(content.via.unwrap(), ["example.com", "example.org"]);
+    }
+
+    #[test]
+    fn space_child_with_invalid_via() {
+        assert_eq!(SpaceChildEventContent::with_via(Vec::new()).unwrap_err(), SpaceViaError::Empty);
+    }
+
+    #[test]
+    fn sort_children() {
+        let ordered = |order: &str| SpaceChildEventContent {
+            order: Some(order.to_owned()),
+            ..SpaceChildEventContent::new()
+        };
+        let ts = |ts: u32| MilliSecondsSinceUnixEpoch(ts.into());
+
+        let mut children = vec![
+            (room_id!("!e:localhost").to_owned(), SpaceChildEventContent::new(), ts(2)),
+            (room_id!("!d:localhost").to_owned(), ordered("a\nb"), ts(2)),
+            (room_id!("!c:localhost").to_owned(), ordered("b"), ts(3)),
+            (room_id!("!b:localhost").to_owned(), SpaceChildEventContent::new(), ts(1)),
+            (room_id!("!a:localhost").to_owned(), ordered("b"), ts(3)),
+            (room_id!("!f:localhost").to_owned(), ordered("a"), ts(4)),
+        ];
+        sort_space_children(&mut children);
+
+        let room_ids: Vec<_> = children.iter().map(|(room_id, _, _)| room_id.as_str()).collect();
+        assert_eq!(
+            room_ids,
+            [
+                "!f:localhost",
+                "!a:localhost",
+                "!c:localhost",
+                "!b:localhost",
+                "!d:localhost",
+                "!e:localhost"
+            ]
+        );
+    }
+
     #[test]
     fn hierarchy_space_child_deserialization() {
         let json = json!({
@@ -5,6 +5,7 @@
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
+use super::{validate_via, SpaceViaError};
 use crate::{OwnedRoomId, OwnedServerName};
 
 /// The content of an `m.space.parent` event.
@@ -38,13 +39,23 @@ impl SpaceParentEventContent {
     pub fn new(canonical: bool) -> Self {
         Self { via: None, canonical }
     }
+
+    /// Creates a new non-canonical `ParentEventContent` with the given `via` servers.
+    ///
+    /// Duplicate servers are removed, keeping the first occurrence of each server. Returns an
+    /// error if `servers` is empty, since an empty `via` means that the parent is not valid, or if
+    /// it contains more than [`MAX_VIA_SERVERS`](super::MAX_VIA_SERVERS) unique servers.
+    pub fn with_via(servers: Vec<OwnedServerName>) -> Result<Self, SpaceViaError> {
+        let via = validate_via(&servers)?;
+        Ok(Self { via: Some(via), canonical: false })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use serde_json::{json, to_value as to_json_value};
 
-    use super::SpaceParentEventContent;
+    use super::{SpaceParentEventContent, SpaceViaError};
     use crate::server_name;
 
     #[test]
@@ -70,4 +81,21 @@ mod tests {
 
         assert_eq!(to_json_value(&content).unwrap(), json);
     }
+
+    #[test]
+    fn space_parent_with_via() {
+        let content = SpaceParentEventContent::with_via(vec![
+            server_name!("example.com").to_owned(),
+            server_name!("example.org").to_owned(),
+            server_name!("example.com").to_owned(),
+        ])
+        .unwrap();
+        assert_eq!(content.via.unwrap(), ["example.com", "example.org"]);
+        assert!(!content.canonical);
+
+        assert_eq!(
+            SpaceParentEventContent::with_via(Vec::new()).unwrap_err(),
+            SpaceViaError::Empty
+        );
+    }
 }
@@ -38,6 +38,11 @@ impl<C: MessageLikeEventContent> MessageLikeUnsigned<C> {
     pub fn new() -> Self {
         Self { age: None, transaction_id: None, relations: BundledMessageLikeRelations::default() }
     }
+
+    /// Create a new `Unsigned` with the given transaction ID.
+    pub fn with_transaction_id(transaction_id: OwnedTransactionId) -> Self {
+        Self { transaction_id: Some(transaction_id), ..Self::new() }
+    }
 }
 
 impl<C: MessageLikeEventContent> Default for MessageLikeUnsigned<C> {
@@ -87,6 +92,11 @@ impl<C: PossiblyRedactedStateEventContent> StateUnsigned<C> {
     pub fn new() -> Self {
         Self { age: None, transaction_id: None, prev_content: None, relations: Default::default() }
     }
+
+    /// Create a new `Unsigned` with the given transaction ID.
+    pub fn with_transaction_id(transaction_id: OwnedTransactionId) -> Self {
+        Self { transaction_id: Some(transaction_id), ..Self::new() }
+    }
 }
 
 impl<C: PossiblyRedactedStateEventContent> CanBeEmpty for StateUnsigned<C> {
@@ -151,3 +161,45 @@ pub struct UnsignedRoomRedactionEvent {
     #[serde(default)]
     pub unsigned: MessageLikeUnsigned<RoomRedactionEventContent>,
 }
+
+#[cfg(test)]
+mod tests {
+    use js_int::int;
+    use serde_json::{from_value as from_json_value, json};
+
+    use super::{MessageLikeUnsigned, StateUnsigned};
+    use crate::{
+        events::{
+            room::topic::PossiblyRedactedRoomTopicEventContent, sticker::StickerEventContent,
+        },
+        serde::CanBeEmpty,
+    };
+
+    #[test]
+    fn message_like_unsigned_with_transaction_id() {
+        let unsigned =
+            MessageLikeUnsigned::<StickerEventContent>::with_transaction_id("txn".into());
+        assert_eq!(unsigned.transaction_id.as_deref().unwrap(), "txn");
+        assert_eq!(unsigned.age, None);
+        assert!(!unsigned.is_empty());
+
+        let unsigned = from_json_value::<MessageLikeUnsigned<StickerEventContent>>(json!({
+            "age": 1234,
+            "transaction_id": "txn",
+        }))
+        .unwrap();
+        assert_eq!(unsigned.transaction_id.as_deref().unwrap(), "txn");
+        assert_eq!(unsigned.age, Some(int!(1234)));
+    }
+
+    #[test]
+    fn state_unsigned_with_transaction_id() {
+        let unsigned = StateUnsigned::<PossiblyRedactedRoomTopicEventContent>::with_transaction_id(
+            "txn".into(),
+        );
+        assert_eq!(unsigned.transaction_id.as_deref().unwrap(), "txn");
+        assert_eq!(unsigned.age, None);
+        assert!(unsigned.prev_content.is_none());
+        assert!(!unsigned.is_empty());
+    }
+}
@@ -21,15 +21,16 @@ pub use self::{
     },
     key_name::{KeyName, OwnedKeyName},
     matrix_uri::{MatrixToUri, MatrixUri},
-    mxc_uri::{MxcUri, OwnedMxcUri},
+    mxc_uri::{MxcUri, OwnedMxcUri, ThumbnailMethod},
     room_alias_id::{OwnedRoomAliasId, RoomAliasId},
     room_id::{OwnedRoomId, RoomId},
-    room_or_room_alias_id::{OwnedRoomOrAliasId, RoomOrAliasId},
+    room_or_room_alias_id::{OwnedRoomOrAliasId, RoomOrAlias, RoomOrAliasId},
     room_version_id::RoomVersionId,
-    server_name::{OwnedServerName, ServerName},
+    server_name::{dedup_valid_servers, DelegatedTarget, OwnedServerName, ServerName},
     session_id::{OwnedSessionId, SessionId},
     signatures::{DeviceSignatures, EntitySignatures, ServerSignatures, Signatures},
     transaction_id::{OwnedTransactionId, TransactionId},
+    user_device::UserDevice,
     user_id::{OwnedUserId, UserId},
     voip_id::{OwnedVoipId, VoipId},
     voip_version_id::VoipVersionId,
@@ -59,17 +60,42 @@ mod server_name;
 mod session_id;
 mod signatures;
 mod transaction_id;
+mod user_device;
 mod voip_id;
 mod voip_version_id;
 
+/// The characters used by default to generate random identifier localparts: ASCII letters and
+/// digits.
+#[cfg(feature = "rand")]
+const DEFAULT_LOCALPART_CHARSET: &[u8] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
 /// Generates a random identifier localpart.
+///
+/// The localpart is made of ASCII letters and digits.
 #[cfg(feature = "rand")]
 fn generate_localpart(length: usize) -> Box<str> {
-    use rand::Rng as _;
-    rand::thread_rng()
-        .sample_iter(&rand::distributions::Alphanumeric)
-        .map(char::from)
-        .take(length)
+    generate_localpart_with_charset(length, DEFAULT_LOCALPART_CHARSET)
+}
+
+/// Generates a random string of the given length, using only characters from the given
+/// character set.
+///
+/// This can be used to generate opaque identifiers, like transaction IDs, that must avoid
+/// certain characters. The identifiers generated by ruma use ASCII letters and digits.
+///
+/// # Panics
+///
+/// Panics if `charset` is empty, or if it contains bytes that are not ASCII characters.
+#[cfg(feature = "rand")]
+pub fn generate_localpart_with_charset(length: usize, charset: &[u8]) -> Box<str> {
+    use rand::seq::SliceRandom as _;
+
+    assert!(charset.is_ascii(), "charset must only contain ASCII characters");
+
+    let mut rng = rand::thread_rng();
+    (0..length)
+        .map(|_| char::from(*charset.choose(&mut rng).expect("charset must not be empty")))
         .collect::<String>()
         .into_boxed_str()
 }
@@ -189,3 +215,23 @@ macro_rules! user_id {
         $crate::_macros::user_id!($crate, $s)
     };
 }
+
+#[cfg(all(test, feature = "rand"))]
+mod tests {
+    use super::{generate_localpart, generate_localpart_with_charset};
+
+    #[test]
+    fn generate_localpart_is_alphanumeric() {
+        let localpart = generate_localpart(32);
+        assert_eq!(localpart.len(), 32);
+        assert!(localpart.chars().all(|c| c.is_ascii_alphanumeric()));
+    }
+
+    #[test]
+    fn generate_localpart_within_charset() {
+        let charset = b"abc-_";
+        let localpart = generate_localpart_with_charset(64, charset);
+        assert_eq!(localpart.len(), 64);
+        assert!(localpart.bytes().all(|b| charset.contains(&b)));
+    }
+}
@@ -36,7 +36,21 @@ impl DeviceId {
     #[cfg(feature = "rand")]
     #[allow(clippy::new_ret_no_self)]
     pub fn new() -> OwnedDeviceId {
-        Self::from_borrowed(&generate_localpart(8)).to_owned()
+        Self::new_with_len(8)
+    }
+
+    /// Generates a random `DeviceId` of `len` characters, suitable for assignment to a new device.
+    #[cfg(feature = "rand")]
+    pub fn new_with_len(len: usize) -> OwnedDeviceId {
+        Self::from_borrowed(&generate_localpart(len)).to_owned()
+    }
+
+    /// Checks whether this device ID is equal to the given one, ignoring ASCII case.
+    ///
+    /// Device IDs are case-sensitive, so this should only be used to match device IDs coming
+    /// from sources that don't preserve their case.
+    pub fn eq_ignore_ascii_case(&self, other: &DeviceId) -> bool {
+        self.as_str().eq_ignore_ascii_case(other.as_str())
     }
 }
 
@@ -49,6 +63,13 @@ mod tests {
         assert_eq!(DeviceId::new().as_str().len(), 8);
     }
 
+    #[test]
+    fn generate_device_id_with_len() {
+        let device_id = DeviceId::new_with_len(32);
+        assert_eq!(device_id.as_str().len(), 32);
+        assert_ne!(device_id, DeviceId::new_with_len(32));
+    }
+
     #[test]
     fn create_device_id_from_str() {
         let ref_id: &DeviceId = "abcdefgh".into();
@@ -67,4 +88,27 @@ mod tests {
         let device_id: OwnedDeviceId = box_str.into();
         assert_eq!(device_id.as_str(), "ijklmnop");
     }
+
+    #[test]
+    fn compare_device_id_with_str() {
+        let device_id = <&DeviceId>::from("ABCdef12");
+        assert_eq!(device_id, "ABCdef12");
+        assert_eq!(*device_id, *"ABCdef12");
+        assert_eq!(device_id, &"ABCdef12".to_owned());
+        assert_eq!("ABCdef12", device_id);
+        assert_ne!(device_id, "abcdef12");
+
+        let owned_device_id: OwnedDeviceId = device_id.to_owned();
+        assert_eq!(owned_device_id, "ABCdef12");
+        assert_eq!(owned_device_id, "ABCdef12".to_owned());
+        assert_eq!("ABCdef12".to_owned(), owned_device_id);
+    }
+
+    #[test]
+    fn device_id_eq_ignore_ascii_case() {
+        let device_id = <&DeviceId>::from("ABCdef12");
+        assert!(device_id.eq_ignore_ascii_case("abcDEF12".into()));
+        assert!(device_id.eq_ignore_ascii_case(device_id));
+        assert!(!device_id.eq_ignore_ascii_case("abcdef13".into()));
+    }
 }
@@ -69,6 +69,14 @@ impl EventId {
         self.colon_idx().map(|idx| ServerName::from_borrowed(&self.as_str()[idx + 1..]))
     }
 
+    /// Whether this event ID uses the original format as used by Matrix room versions 1 and 2,
+    /// with a "localpart" followed by the server name.
+    ///
+    /// Returns `false` for event IDs that are a hash of the event, as used by later room versions.
+    pub fn is_v1_format(&self) -> bool {
+        self.colon_idx().is_some()
+    }
+
     fn colon_idx(&self) -> Option<usize> {
         self.as_str().find(':')
     }
@@ -115,6 +123,19 @@ mod tests {
 
         assert!(id_str.starts_with('$'));
         assert_eq!(id_str.len(), 31);
+        assert!(event_id.is_v1_format());
+        assert_eq!(event_id.server_name().unwrap(), "example.com");
+    }
+
+    #[test]
+    fn event_id_format() {
+        assert!(<&EventId>::try_from("$39hvsi03hlne:example.com").unwrap().is_v1_format());
+        assert!(!<&EventId>::try_from("$acR1l0raoZnm60CBwAVgqbZqoO/mYU81xysh1u7XcJk")
+            .unwrap()
+            .is_v1_format());
+        assert!(!<&EventId>::try_from("$Rqnc-F-dvnEYJTyHq_iKxU2bZ1CI92-kuZq3a5lr5Zg")
+            .unwrap()
+            .is_v1_format());
     }
 
     #[test]
@@ -2,24 +2,39 @@
 //!
 //! [MXC URI]: https://spec.matrix.org/latest/client-server-api/#matrix-content-mxc-uris
 
-use std::num::NonZeroU8;
+use std::num::NonZeroUsize;
 
+use js_int::UInt;
 use ruma_identifiers_validation::{error::MxcUriError, mxc_uri::validate};
 use ruma_macros::IdZst;
 
 use super::ServerName;
+use crate::{serde::StringEnum, PrivOwnedStr};
 
 type Result<T, E = MxcUriError> = std::result::Result<T, E>;
 
 /// A URI that should be a Matrix-spec compliant [MXC URI].
 ///
+/// Comparing two `MxcUri`s compares their underlying strings byte by byte. Use
+/// [`MxcUri::normalized()`] to compare URIs that might only differ by the case of their scheme or
+/// by surrounding whitespace.
+///
 /// [MXC URI]: https://spec.matrix.org/latest/client-server-api/#matrix-content-mxc-uris
-
 #[repr(transparent)]
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, IdZst)]
 pub struct MxcUri(str);
 
 impl MxcUri {
+    /// Creates an `MxcUri` from a server name and a media ID.
+    ///
+    /// Returns an error if the media ID contains characters that are not allowed in an MXC URI.
+    pub fn from_parts(server_name: &ServerName, media_id: &str) -> Result<OwnedMxcUri> {
+        let uri = format!("mxc://{server_name}/{media_id}");
+        validate(&uri)?;
+
+        Ok(Self::from_borrowed(&uri).to_owned())
+    }
+
     /// If this is a valid MXC URI, returns the media ID.
     pub fn media_id(&self) -> Result<&str> {
         self.parts().map(|(_, s)| s)
@@ -32,13 +47,60 @@ impl MxcUri {
 
     /// If this is a valid MXC URI, returns a `(server_name, media_id)` tuple, else it returns the
     /// error.
+    ///
+    /// A trailing `?query` or `#fragment` is not considered part of the media ID and is ignored
+    /// here, it can be accessed with [`MxcUri::query()`] and [`MxcUri::fragment()`]. Note that
+    /// [`MxcUri::validate()`] still rejects URIs containing them.
     pub fn parts(&self) -> Result<(&ServerName, &str)> {
-        self.extract_slash_idx().map(|idx| {
-            (
-                ServerName::from_borrowed(&self.as_str()[6..idx.get() as usize]),
-                &self.as_str()[idx.get() as usize + 1..],
-            )
-        })
+        let uri = self.without_suffix();
+        validate(uri)
+            .map(|idx| (ServerName::from_borrowed(&uri[6..idx.get()]), &uri[idx.get() + 1..]))
+    }
+
+    /// Returns the query of this URI, the part after the first `?` and before any `#`, if any.
+    pub fn query(&self) -> Option<&str> {
+        self.without_fragment().split_once('?').map(|(_, query)| query)
+    }
+
+    /// Returns the fragment of this URI, the part after the first `#`, if any.
+    pub fn fragment(&self) -> Option<&str> {
+        self.as_str().split_once('#').map(|(_, fragment)| fragment)
+    }
+
+    /// If this is a valid MXC URI, returns the path to download the media through the
+    /// authenticated media endpoint, e.g. `/_matrix/client/v1/media/download/{server}/{media}`.
+    pub fn to_download_uri(&self) -> Result<String> {
+        let (server_name, media_id) = self.parts()?;
+        Ok(format!("/_matrix/client/v1/media/download/{server_name}/{media_id}"))
+    }
+
+    /// If this is a valid MXC URI, returns the path to get a thumbnail of the media through the
+    /// authenticated media endpoint, with the given dimensions and resizing method.
+    pub fn to_thumbnail_uri(
+        &self,
+        width: UInt,
+        height: UInt,
+        method: ThumbnailMethod,
+    ) -> Result<String> {
+        let (server_name, media_id) = self.parts()?;
+        Ok(format!(
+            "/_matrix/client/v1/media/thumbnail/{server_name}/{media_id}\
+             ?width={width}&height={height}&method={method}"
+        ))
+    }
+
+    /// Returns a normalized copy of this URI.
+    ///
+    /// The surrounding whitespace is removed and the `mxc://` scheme is converted to lowercase.
+    pub fn normalized(&self) -> OwnedMxcUri {
+        let uri = self.as_str().trim();
+
+        match uri.get(..6) {
+            Some(scheme) if scheme.eq_ignore_ascii_case("mxc://") => {
+                Self::from_borrowed(&format!("mxc://{}", &uri[6..])).to_owned()
+            }
+            _ => Self::from_borrowed(uri).to_owned(),
+        }
     }
 
     /// Validates the URI and returns an error if it failed.
@@ -54,16 +116,45 @@ impl MxcUri {
 
     // convenience method for calling validate(self)
     #[inline(always)]
-    fn extract_slash_idx(&self) -> Result<NonZeroU8> {
+    fn extract_slash_idx(&self) -> Result<NonZeroUsize> {
         validate(self.as_str())
     }
+
+    // the URI without the fragment
+    fn without_fragment(&self) -> &str {
+        self.as_str().split_once('#').map_or(self.as_str(), |(uri, _)| uri)
+    }
+
+    // the URI without the query and the fragment
+    fn without_suffix(&self) -> &str {
+        let uri = self.without_fragment();
+        uri.split_once('?').map_or(uri, |(uri, _)| uri)
+    }
+}
+
+/// The desired resizing method of a thumbnail.
+#[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/doc/string_enum.md"))]
+#[derive(Clone, PartialEq, Eq, StringEnum)]
+#[ruma_enum(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ThumbnailMethod {
+    /// Crop the original to produce the requested image dimensions.
+    Crop,
+
+    /// Maintain the original aspect ratio of the source image.
+    Scale,
+
+    #[doc(hidden)]
+    _Custom(PrivOwnedStr),
 }
 
 #[cfg(test)]
 mod tests {
+    use js_int::uint;
     use ruma_identifiers_validation::error::MxcUriError;
 
-    use super::{MxcUri, OwnedMxcUri};
+    use super::{MxcUri, OwnedMxcUri, ThumbnailMethod};
+    use crate::server_name;
 
     #[test]
     fn parse_mxc_uri() {
@@ -76,6 +167,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_mxc_uri_with_long_server_name() {
+        let server_name = format!("{}.org", "a".repeat(296));
+        let mxc = MxcUri::from_parts(server_name.as_str().try_into().unwrap(), "mediaid").unwrap();
+
+        assert!(mxc.is_valid());
+        assert_eq!(mxc.parts(), Ok((server_name.as_str().try_into().unwrap(), "mediaid")));
+    }
+
     #[test]
     fn parse_mxc_uri_without_media_id() {
         let mxc = Box::<MxcUri>::from("mxc://127.0.0.1");
@@ -84,6 +184,23 @@ mod tests {
         assert_eq!(mxc.parts(), Err(MxcUriError::MissingSlash));
     }
 
+    #[test]
+    fn parse_mxc_uri_without_server_name() {
+        let mxc = Box::<MxcUri>::from("mxc:///mediaonly");
+
+        assert!(!mxc.is_valid());
+        assert_eq!(mxc.parts(), Err(MxcUriError::ServerNameMalformed));
+        assert_eq!(mxc.server_name(), Err(MxcUriError::ServerNameMalformed));
+    }
+
+    #[test]
+    fn parse_mxc_uri_with_invalid_server_name() {
+        let mxc = Box::<MxcUri>::from("mxc://[::1/mediaid");
+
+        assert!(!mxc.is_valid());
+        assert_eq!(mxc.parts(), Err(MxcUriError::ServerNameMalformed));
+    }
+
     #[test]
     fn parse_mxc_uri_without_protocol() {
         assert!(!Box::<MxcUri>::from("127.0.0.1/asd32asdfasdsd").is_valid());
@@ -110,4 +227,94 @@ mod tests {
             Ok(("server".try_into().expect("Failed to create ServerName"), "1234id"))
         );
     }
+
+    #[test]
+    fn mxc_uri_from_parts() {
+        let mxc = MxcUri::from_parts(server_name!("example.org"), "abcDEF123-").unwrap();
+
+        assert_eq!(mxc.as_str(), "mxc://example.org/abcDEF123-");
+        assert_eq!(mxc.parts(), Ok((server_name!("example.org"), "abcDEF123-")));
+    }
+
+    #[test]
+    fn mxc_uri_from_parts_invalid_media_id() {
+        assert_eq!(
+            MxcUri::from_parts(server_name!("example.org"), "abc/def"),
+            Err(MxcUriError::MediaIdMalformed)
+        );
+        assert_eq!(
+            MxcUri::from_parts(server_name!("example.org"), "abc\ndef"),
+            Err(MxcUriError::MediaIdMalformed)
+        );
+    }
+
+    #[test]
+    fn mxc_uri_to_authenticated_media_uris() {
+        let mxc = <&MxcUri>::from("mxc://example.org/abcdef");
+
+        assert_eq!(
+            mxc.to_download_uri().unwrap(),
+            "/_matrix/client/v1/media/download/example.org/abcdef"
+        );
+        assert_eq!(
+            mxc.to_thumbnail_uri(uint!(64), uint!(32), ThumbnailMethod::Scale).unwrap(),
+            "/_matrix/client/v1/media/thumbnail/example.org/abcdef?width=64&height=32&method=scale"
+        );
+    }
+
+    #[test]
+    fn invalid_mxc_uri_to_authenticated_media_uris() {
+        let mxc = <&MxcUri>::from("mxc://example.org");
+
+        assert_eq!(mxc.to_download_uri(), Err(MxcUriError::MissingSlash));
+        assert_eq!(
+            mxc.to_thumbnail_uri(uint!(64), uint!(32), ThumbnailMethod::Crop),
+            Err(MxcUriError::MissingSlash)
+        );
+    }
+
+    #[test]
+    fn parse_mxc_uri_with_query_and_fragment() {
+        let mxc = <&MxcUri>::from("mxc://server/id?width=96");
+        assert_eq!(mxc.parts(), Ok((server_name!("server"), "id")));
+        assert_eq!(mxc.media_id(), Ok("id"));
+        assert_eq!(mxc.query(), Some("width=96"));
+        assert_eq!(mxc.fragment(), None);
+        assert_eq!(mxc.validate(), Err(MxcUriError::MediaIdMalformed));
+        assert_eq!(mxc.to_download_uri().unwrap(), "/_matrix/client/v1/media/download/server/id");
+
+        let mxc = <&MxcUri>::from("mxc://server/id?width=96&height=96#thumb");
+        assert_eq!(mxc.media_id(), Ok("id"));
+        assert_eq!(mxc.query(), Some("width=96&height=96"));
+        assert_eq!(mxc.fragment(), Some("thumb"));
+        assert!(!mxc.is_valid());
+
+        let mxc = <&MxcUri>::from("mxc://server/id#thumb?width=96");
+        assert_eq!(mxc.media_id(), Ok("id"));
+        assert_eq!(mxc.query(), None);
+        assert_eq!(mxc.fragment(), Some("thumb?width=96"));
+
+        let mxc = <&MxcUri>::from("mxc://server/id");
+        assert_eq!(mxc.query(), None);
+        assert_eq!(mxc.fragment(), None);
+    }
+
+    #[test]
+    fn normalize_mxc_uri() {
+        let mxc = <&MxcUri>::from("MXC://server/id");
+        assert_eq!(mxc.normalized().as_str(), "mxc://server/id");
+
+        let mxc = <&MxcUri>::from(" Mxc://server/id\n");
+        assert_eq!(mxc.normalized().as_str(), "mxc://server/id");
+        assert!(mxc.normalized().is_valid());
+
+        let mxc = <&MxcUri>::from("mxc://server/id");
+        assert_eq!(mxc.normalized(), mxc);
+    }
+
+    #[test]
+    fn serialize_thumbnail_method() {
+        assert_eq!(serde_json::to_string(&ThumbnailMethod::Crop).unwrap(), r#""crop""#);
+        assert_eq!(serde_json::to_string(&ThumbnailMethod::Scale).unwrap(), r#""scale""#);
+    }
 }
@@ -241,6 +241,9 @@ mod tests {
 
         assert!(id_str.starts_with('!'));
         assert_eq!(id_str.len(), 31);
+        assert_eq!(room_id.server_name(), "example.com");
+        assert_eq!(room_id.localpart().len(), 18);
+        assert_eq!(<&RoomId>::try_from(id_str).unwrap(), room_id);
     }
 
     #[test]
@@ -283,6 +286,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn room_id_parts() {
+        let room_id = <&RoomId>::try_from("!abc:example.com").unwrap();
+        assert_eq!(room_id.localpart(), "abc");
+        assert_eq!(room_id.server_name(), "example.com");
+
+        let room_id = <&RoomId>::try_from("!abc:example.com:5000").unwrap();
+        assert_eq!(room_id.localpart(), "abc");
+        assert_eq!(room_id.server_name(), "example.com:5000");
+    }
+
     #[test]
     fn missing_room_id_sigil() {
         assert_eq!(
@@ -42,31 +42,38 @@ impl RoomOrAliasId {
 
     /// Whether this is a room id (starts with `'!'`)
     pub fn is_room_id(&self) -> bool {
-        self.variant() == Variant::RoomId
+        matches!(self.variant(), RoomOrAlias::RoomId(_))
     }
 
     /// Whether this is a room alias id (starts with `'#'`)
     pub fn is_room_alias_id(&self) -> bool {
-        self.variant() == Variant::RoomAliasId
+        matches!(self.variant(), RoomOrAlias::RoomAliasId(_))
     }
 
-    fn colon_idx(&self) -> usize {
-        self.as_str().find(':').unwrap()
-    }
-
-    fn variant(&self) -> Variant {
+    /// Get the room ID or room alias ID contained in this identifier.
+    pub fn variant(&self) -> RoomOrAlias<'_> {
         match self.as_str().bytes().next() {
-            Some(b'!') => Variant::RoomId,
-            Some(b'#') => Variant::RoomAliasId,
+            Some(b'!') => RoomOrAlias::RoomId(RoomId::from_borrowed(self.as_str())),
+            Some(b'#') => RoomOrAlias::RoomAliasId(RoomAliasId::from_borrowed(self.as_str())),
             _ => unsafe { unreachable_unchecked() },
         }
     }
+
+    fn colon_idx(&self) -> usize {
+        self.as_str().find(':').unwrap()
+    }
 }
 
-#[derive(PartialEq, Eq)]
-enum Variant {
-    RoomId,
-    RoomAliasId,
+/// A reference to either a room ID or a room alias ID, as returned by
+/// [`RoomOrAliasId::variant`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)]
+pub enum RoomOrAlias<'a> {
+    /// A room ID.
+    RoomId(&'a RoomId),
+
+    /// A room alias ID.
+    RoomAliasId(&'a RoomAliasId),
 }
 
 impl<'a> From<&'a RoomId> for &'a RoomOrAliasId {
@@ -100,8 +107,8 @@ impl<'a> TryFrom<&'a RoomOrAliasId> for &'a RoomId {
 
     fn try_from(id: &'a RoomOrAliasId) -> Result<&'a RoomId, &'a RoomAliasId> {
         match id.variant() {
-            Variant::RoomId => Ok(RoomId::from_borrowed(id.as_str())),
-            Variant::RoomAliasId => Err(RoomAliasId::from_borrowed(id.as_str())),
+            RoomOrAlias::RoomId(room_id) => Ok(room_id),
+            RoomOrAlias::RoomAliasId(room_alias_id) => Err(room_alias_id),
         }
     }
 }
@@ -111,8 +118,8 @@ impl<'a> TryFrom<&'a RoomOrAliasId> for &'a RoomAliasId {
 
     fn try_from(id: &'a RoomOrAliasId) -> Result<&'a RoomAliasId, &'a RoomId> {
         match id.variant() {
-            Variant::RoomAliasId => Ok(RoomAliasId::from_borrowed(id.as_str())),
-            Variant::RoomId => Err(RoomId::from_borrowed(id.as_str())),
+            RoomOrAlias::RoomAliasId(room_alias_id) => Ok(room_alias_id),
+            RoomOrAlias::RoomId(room_id) => Err(room_id),
         }
     }
 }
@@ -123,8 +130,8 @@ impl TryFrom<OwnedRoomOrAliasId> for OwnedRoomId {
     fn try_from(id: OwnedRoomOrAliasId) -> Result<OwnedRoomId, OwnedRoomAliasId> {
         // FIXME: Don't allocate
         match id.variant() {
-            Variant::RoomId => Ok(RoomId::from_borrowed(id.as_str()).to_owned()),
-            Variant::RoomAliasId => Err(RoomAliasId::from_borrowed(id.as_str()).to_owned()),
+            RoomOrAlias::RoomId(room_id) => Ok(room_id.to_owned()),
+            RoomOrAlias::RoomAliasId(room_alias_id) => Err(room_alias_id.to_owned()),
         }
     }
 }
@@ -135,16 +142,16 @@ impl TryFrom<OwnedRoomOrAliasId> for OwnedRoomAliasId {
     fn try_from(id: OwnedRoomOrAliasId) -> Result<OwnedRoomAliasId, OwnedRoomId> {
         // FIXME: Don't allocate
         match id.variant() {
-            Variant::RoomAliasId => Ok(RoomAliasId::from_borrowed(id.as_str()).to_owned()),
-            Variant::RoomId => Err(RoomId::from_borrowed(id.as_str()).to_owned()),
+            RoomOrAlias::RoomAliasId(room_alias_id) => Ok(room_alias_id.to_owned()),
+            RoomOrAlias::RoomId(room_id) => Err(room_id.to_owned()),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{OwnedRoomOrAliasId, RoomOrAliasId};
-    use crate::IdParseError;
+    use super::{OwnedRoomOrAliasId, RoomOrAlias, RoomOrAliasId};
+    use crate::{room_alias_id, room_id, IdParseError};
 
     #[test]
     fn valid_room_id_or_alias_id_with_a_room_alias_id() {
@@ -216,4 +223,16 @@ mod tests {
                 .expect("Failed to create RoomAliasId.")
         );
     }
+
+    #[test]
+    fn variant_of_room_id() {
+        let id = <&RoomOrAliasId>::try_from("!29fhd83h92h0:example.com").unwrap();
+        assert_eq!(id.variant(), RoomOrAlias::RoomId(room_id!("!29fhd83h92h0:example.com")));
+    }
+
+    #[test]
+    fn variant_of_room_alias_id() {
+        let id = <&RoomOrAliasId>::try_from("#ruma:example.com").unwrap();
+        assert_eq!(id.variant(), RoomOrAlias::RoomAliasId(room_alias_id!("#ruma:example.com")));
+    }
 }
@@ -56,11 +56,42 @@ pub enum RoomVersionId {
     /// A version 10 room.
     V10,
 
+    /// A version 11 room.
+    V11,
+
     #[doc(hidden)]
     _Custom(CustomRoomVersion),
 }
 
 impl RoomVersionId {
+    /// The default room version recommended by the latest version of the Matrix specification
+    /// supported by this crate.
+    ///
+    /// This should be used as the room version of new rooms, unless a specific one is needed.
+    pub fn default_stable() -> Self {
+        // <https://spec.matrix.org/v1.6/rooms/#complete-list-of-room-versions>
+        Self::V10
+    }
+
+    /// The room versions known by this crate, in the order they were introduced.
+    pub fn supported() -> &'static [Self] {
+        static SUPPORTED: [RoomVersionId; 11] = [
+            RoomVersionId::V1,
+            RoomVersionId::V2,
+            RoomVersionId::V3,
+            RoomVersionId::V4,
+            RoomVersionId::V5,
+            RoomVersionId::V6,
+            RoomVersionId::V7,
+            RoomVersionId::V8,
+            RoomVersionId::V9,
+            RoomVersionId::V10,
+            RoomVersionId::V11,
+        ];
+
+        &SUPPORTED
+    }
+
     /// Creates a string slice from this `RoomVersionId`.
     pub fn as_str(&self) -> &str {
         // FIXME: Add support for non-`str`-deref'ing types for fallback to AsRefStr derive and
@@ -76,6 +107,7 @@ impl RoomVersionId {
             Self::V8 => "8",
             Self::V9 => "9",
             Self::V10 => "10",
+            Self::V11 => "11",
             Self::_Custom(version) => version.as_str(),
         }
     }
@@ -84,6 +116,44 @@ impl RoomVersionId {
     pub fn as_bytes(&self) -> &[u8] {
         self.as_str().as_bytes()
     }
+
+    /// Whether the `creator` field of the content of an `m.room.create` event is kept during
+    /// redaction in this room version.
+    ///
+    /// Starting from room version 11, this field was removed from the content, and all of the
+    /// content is kept instead, see [`redaction_keeps_all_create_content`].
+    ///
+    /// Custom room versions are assumed to behave like room versions prior to 11.
+    ///
+    /// [`redaction_keeps_all_create_content`]: Self::redaction_keeps_all_create_content
+    pub fn redaction_keeps_create_creator(&self) -> bool {
+        !matches!(self, Self::V11)
+    }
+
+    /// Whether all the fields of the content of an `m.room.create` event are kept during
+    /// redaction in this room version.
+    ///
+    /// Custom room versions are assumed to behave like room versions prior to 11, which don't.
+    pub fn redaction_keeps_all_create_content(&self) -> bool {
+        matches!(self, Self::V11)
+    }
+
+    /// Whether the `redacts` field of `m.room.redaction` events is in the content in this room
+    /// version, rather than at the top level of the event.
+    ///
+    /// Custom room versions are assumed to behave like room versions prior to 11, which don't.
+    pub fn redacts_in_content(&self) -> bool {
+        matches!(self, Self::V11)
+    }
+
+    /// Whether event IDs in this room version are derived from the reference hash of the event.
+    ///
+    /// Room versions 1 and 2 use event IDs of the form `$opaque_id:server_name` instead.
+    ///
+    /// Custom room versions are assumed to behave like room versions 3 and later, which do.
+    pub fn uses_event_id_as_hash(&self) -> bool {
+        !matches!(self, Self::V1 | Self::V2)
+    }
 }
 
 impl From<RoomVersionId> for String {
@@ -99,6 +169,7 @@ impl From<RoomVersionId> for String {
             RoomVersionId::V8 => "8".to_owned(),
             RoomVersionId::V9 => "9".to_owned(),
             RoomVersionId::V10 => "10".to_owned(),
+            RoomVersionId::V11 => "11".to_owned(),
             RoomVersionId::_Custom(version) => version.into(),
         }
     }
@@ -172,6 +243,7 @@ where
         "8" => RoomVersionId::V8,
         "9" => RoomVersionId::V9,
         "10" => RoomVersionId::V10,
+        "11" => RoomVersionId::V11,
         custom => {
             ruma_identifiers_validation::room_version_id::validate(custom)?;
             RoomVersionId::_Custom(CustomRoomVersion(room_version_id.into()))
@@ -260,6 +332,19 @@ mod tests {
     use super::RoomVersionId;
     use crate::IdParseError;
 
+    #[test]
+    fn default_stable_is_supported() {
+        assert!(RoomVersionId::supported().contains(&RoomVersionId::default_stable()));
+    }
+
+    #[test]
+    fn supported_versions_are_known() {
+        for version in RoomVersionId::supported() {
+            assert!(!matches!(version, RoomVersionId::_Custom(_)));
+            assert_eq!(RoomVersionId::try_from(version.as_str()).unwrap(), *version);
+        }
+    }
+
     #[test]
     fn valid_version_1_room_version_id() {
         assert_eq!(
@@ -387,4 +472,37 @@ mod tests {
             IdParseError::InvalidCharacters
         );
     }
+
+    #[test]
+    fn redaction_keeps_create_creator() {
+        assert!(RoomVersionId::V1.redaction_keeps_create_creator());
+        assert!(RoomVersionId::V6.redaction_keeps_create_creator());
+        assert!(!RoomVersionId::V11.redaction_keeps_create_creator());
+        assert!(RoomVersionId::try_from("io.ruma.1").unwrap().redaction_keeps_create_creator());
+    }
+
+    #[test]
+    fn redaction_keeps_all_create_content() {
+        assert!(!RoomVersionId::V1.redaction_keeps_all_create_content());
+        assert!(!RoomVersionId::V10.redaction_keeps_all_create_content());
+        assert!(RoomVersionId::V11.redaction_keeps_all_create_content());
+        assert!(!RoomVersionId::try_from("io.ruma.1")
+            .unwrap()
+            .redaction_keeps_all_create_content());
+    }
+
+    #[test]
+    fn redacts_in_content() {
+        assert!(!RoomVersionId::V1.redacts_in_content());
+        assert!(!RoomVersionId::V10.redacts_in_content());
+        assert!(RoomVersionId::V11.redacts_in_content());
+        assert!(!RoomVersionId::try_from("io.ruma.1").unwrap().redacts_in_content());
+    }
+
+    #[test]
+    fn uses_event_id_as_hash() {
+        assert!(!RoomVersionId::V1.uses_event_id_as_hash());
+        assert!(RoomVersionId::V6.uses_event_id_as_hash());
+        assert!(RoomVersionId::V11.uses_event_id_as_hash());
+    }
 }
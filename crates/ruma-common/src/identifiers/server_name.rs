@@ -1,6 +1,6 @@
 //! Matrix-spec compliant server names.
 
-use std::net::Ipv4Addr;
+use std::{collections::BTreeSet, fmt, net::Ipv4Addr};
 
 use ruma_macros::IdZst;
 
@@ -15,6 +15,12 @@ use ruma_macros::IdZst;
 pub struct ServerName(str);
 
 impl ServerName {
+    /// The default port of the server-server API.
+    pub const DEFAULT_FEDERATION_PORT: u16 = 8448;
+
+    /// The default port of the client-server API, which is served over HTTPS.
+    pub const DEFAULT_CLIENT_PORT: u16 = 443;
+
     /// Returns the host of the server name.
     ///
     /// That is: Return the part of the server name before `:<port>` or the full server name if
@@ -45,15 +51,71 @@ impl ServerName {
         })
     }
 
+    /// Returns the port to use to connect to the server-server API of this server name, without
+    /// delegation.
+    ///
+    /// That is: Return the port of the server name, or [`Self::DEFAULT_FEDERATION_PORT`] if there
+    /// is no port.
+    pub fn default_port(&self) -> u16 {
+        self.port().unwrap_or(Self::DEFAULT_FEDERATION_PORT)
+    }
+
     /// Returns true if and only if the server name is an IPv4 or IPv6 address.
     pub fn is_ip_literal(&self) -> bool {
         self.host().parse::<Ipv4Addr>().is_ok() || self.0.starts_with('[')
     }
 }
 
+/// The host and port to connect to for a server name, after resolving its delegation.
+///
+/// The server name in the `m.server` field of a `.well-known/matrix/server` response can be
+/// converted to a `DelegatedTarget`, which uses [`ServerName::default_port`] if it doesn't have a
+/// port. SRV records give the host and port directly.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub struct DelegatedTarget {
+    /// The host to connect to.
+    ///
+    /// IPv6 addresses are enclosed in square brackets.
+    pub host: String,
+
+    /// The port to connect to.
+    pub port: u16,
+}
+
+impl DelegatedTarget {
+    /// Creates a new `DelegatedTarget` with the given host and port.
+    pub fn new(host: String, port: u16) -> Self {
+        Self { host, port }
+    }
+}
+
+impl From<&ServerName> for DelegatedTarget {
+    fn from(server_name: &ServerName) -> Self {
+        Self::new(server_name.host().to_owned(), server_name.default_port())
+    }
+}
+
+impl fmt::Display for DelegatedTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.host, self.port)
+    }
+}
+
+/// Removes the duplicates from the given list of server names, keeping the first occurrence of each
+/// server name.
+///
+/// This is useful for lists of servers that can be used to join a room, like the `via` field of
+/// `m.space.child` events or the `server_name` query parameter of endpoints to join a room.
+pub fn dedup_valid_servers(input: &[OwnedServerName]) -> Vec<OwnedServerName> {
+    let mut seen = BTreeSet::new();
+    input.iter().filter(|server| seen.insert(server.as_str())).cloned().collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use super::ServerName;
+    use super::{dedup_valid_servers, DelegatedTarget, ServerName};
+    use crate::server_name;
 
     #[test]
     fn ipv4_host() {
@@ -152,4 +214,69 @@ mod tests {
         assert!(!server_name.is_ip_literal());
         assert_eq!(server_name.host(), "ruma.io");
     }
+
+    #[test]
+    fn dns_name_host_and_port() {
+        let server_name = <&ServerName>::try_from("example.com:8448").unwrap();
+        assert!(!server_name.is_ip_literal());
+        assert_eq!(server_name.host(), "example.com");
+        assert_eq!(server_name.port(), Some(8448));
+    }
+
+    #[test]
+    fn ipv6_host_and_port() {
+        let server_name = <&ServerName>::try_from("[::1]:8008").unwrap();
+        assert!(server_name.is_ip_literal());
+        assert_eq!(server_name.host(), "[::1]");
+        assert_eq!(server_name.port(), Some(8008));
+    }
+
+    #[test]
+    fn dns_name_without_port() {
+        let server_name = <&ServerName>::try_from("example.com").unwrap();
+        assert!(!server_name.is_ip_literal());
+        assert_eq!(server_name.host(), "example.com");
+        assert_eq!(server_name.port(), None);
+    }
+
+    #[test]
+    fn default_port() {
+        let server_name = <&ServerName>::try_from("example.com:8080").unwrap();
+        assert_eq!(server_name.default_port(), 8080);
+
+        let server_name = <&ServerName>::try_from("example.com").unwrap();
+        assert_eq!(server_name.default_port(), ServerName::DEFAULT_FEDERATION_PORT);
+    }
+
+    #[test]
+    fn delegated_target() {
+        let target = DelegatedTarget::from(server_name!("matrix.example.com:443"));
+        assert_eq!(target, DelegatedTarget::new("matrix.example.com".to_owned(), 443));
+
+        let target = DelegatedTarget::from(server_name!("[::1]"));
+        assert_eq!(target.host, "[::1]");
+        assert_eq!(target.port, 8448);
+        assert_eq!(target.to_string(), "[::1]:8448");
+    }
+
+    #[test]
+    fn dedup_servers() {
+        let servers = vec![
+            server_name!("b.example.org").to_owned(),
+            server_name!("a.example.org").to_owned(),
+            server_name!("b.example.org").to_owned(),
+            server_name!("c.example.org").to_owned(),
+            server_name!("a.example.org").to_owned(),
+        ];
+
+        assert_eq!(
+            dedup_valid_servers(&servers),
+            [
+                server_name!("b.example.org"),
+                server_name!("a.example.org"),
+                server_name!("c.example.org")
+            ]
+        );
+        assert!(dedup_valid_servers(&[]).is_empty());
+    }
 }
@@ -24,3 +24,33 @@ impl TransactionId {
         Self::from_borrowed(&id.simple().to_string()).to_owned()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{OwnedTransactionId, TransactionId};
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generate_transaction_id() {
+        let txn_id = TransactionId::new();
+        assert_eq!(txn_id.as_str().len(), 32);
+        assert!(txn_id.as_str().bytes().all(|b| b.is_ascii_hexdigit()));
+        assert_ne!(txn_id, TransactionId::new());
+    }
+
+    #[test]
+    fn transaction_id_from_str() {
+        let txn_id = <&TransactionId>::from("m1234.5");
+        assert_eq!(txn_id.as_str(), "m1234.5");
+
+        let owned: OwnedTransactionId = "m1234.5".into();
+        assert_eq!(owned, txn_id);
+    }
+
+    #[test]
+    fn serde_transaction_id() {
+        let txn_id: OwnedTransactionId = serde_json::from_str(r#""m1234.5""#).unwrap();
+        assert_eq!(txn_id, "m1234.5");
+        assert_eq!(serde_json::to_string(&txn_id).unwrap(), r#""m1234.5""#);
+    }
+}
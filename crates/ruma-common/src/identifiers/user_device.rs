@@ -0,0 +1,108 @@
+//! Pairs of a user ID and a device ID.
+
+use std::{fmt, str::FromStr};
+
+use super::{IdParseError, OwnedDeviceId, OwnedUserId, UserId};
+
+/// A user ID and the ID of one of the user's devices.
+///
+/// This is useful to flatten maps that are usually keyed by user ID, then by device ID, like
+/// `BTreeMap<OwnedUserId, BTreeMap<OwnedDeviceId, T>>`. Ordering is by user ID, then by device ID,
+/// so the devices of a user are next to each other in such a map.
+///
+/// It is displayed and parsed as the user ID and the device ID separated by a slash:
+///
+/// ```
+/// # use ruma_common::UserDevice;
+/// let user_device: UserDevice = "@alice:example.org/ABCDEFGH".parse().unwrap();
+/// assert_eq!(user_device.user_id, "@alice:example.org");
+/// assert_eq!(user_device.device_id, "ABCDEFGH");
+/// assert_eq!(user_device.to_string(), "@alice:example.org/ABCDEFGH");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::exhaustive_structs)]
+pub struct UserDevice {
+    /// The ID of the user.
+    pub user_id: OwnedUserId,
+
+    /// The ID of the device.
+    pub device_id: OwnedDeviceId,
+}
+
+impl UserDevice {
+    /// Creates a new `UserDevice` with the given user ID and device ID.
+    pub fn new(user_id: OwnedUserId, device_id: OwnedDeviceId) -> Self {
+        Self { user_id, device_id }
+    }
+}
+
+impl fmt::Display for UserDevice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.user_id, self.device_id)
+    }
+}
+
+impl FromStr for UserDevice {
+    type Err = IdParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The server name of the user ID can't contain a slash, so the first slash after the
+        // first colon separates the user ID from the device ID.
+        let colon_idx = s.find(':').ok_or(IdParseError::MissingColon)?;
+        let (user_id, device_id) = match s[colon_idx..].find('/') {
+            Some(slash_idx) => s.split_at(colon_idx + slash_idx),
+            None => return Err(IdParseError::MissingDelimiter),
+        };
+        let device_id = &device_id[1..];
+
+        if device_id.is_empty() {
+            return Err(IdParseError::Empty);
+        }
+
+        Ok(Self { user_id: UserId::parse(user_id)?, device_id: device_id.into() })
+    }
+}
+
+impl TryFrom<&str> for UserDevice {
+    type Error = IdParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UserDevice;
+    use crate::{device_id, user_id, IdParseError};
+
+    #[test]
+    fn display_user_device() {
+        let user_device = UserDevice::new(
+            user_id!("@alice:example.org").to_owned(),
+            device_id!("ABCDEFGH").to_owned(),
+        );
+        assert_eq!(user_device.to_string(), "@alice:example.org/ABCDEFGH");
+    }
+
+    #[test]
+    fn parse_user_device() {
+        let user_device: UserDevice = "@alice:example.org:8448/ABC/DEF".parse().unwrap();
+        assert_eq!(user_device.user_id, "@alice:example.org:8448");
+        assert_eq!(user_device.device_id, "ABC/DEF");
+        assert_eq!(user_device.to_string(), "@alice:example.org:8448/ABC/DEF");
+    }
+
+    #[test]
+    fn parse_invalid_user_device() {
+        assert_eq!(
+            "@alice:example.org".parse::<UserDevice>().unwrap_err(),
+            IdParseError::MissingDelimiter
+        );
+        assert_eq!("@alice:example.org/".parse::<UserDevice>().unwrap_err(), IdParseError::Empty);
+        assert_eq!(
+            "alice:example.org/ABCDEFGH".parse::<UserDevice>().unwrap_err(),
+            IdParseError::MissingLeadingSigil
+        );
+    }
+}
@@ -11,6 +11,7 @@ use serde_json::{value::RawValue as RawJsonValue, Value as JsonValue};
 pub mod base64;
 mod buf;
 pub mod can_be_empty;
+pub mod comma_separated;
 mod cow;
 pub mod duration;
 pub mod json_string;
@@ -31,6 +32,9 @@ pub use self::{
     },
 };
 
+#[cfg(feature = "strict-deserialize")]
+pub(crate) use self::raw::{denying_unknown_fields, from_json_str_deny_unknown_fields};
+
 /// The inner type of [`JsonValue::Object`].
 pub type JsonObject = serde_json::Map<String, JsonValue>;
 
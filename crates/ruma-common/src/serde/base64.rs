@@ -73,6 +73,11 @@ impl<C: Base64Config, B: AsRef<[u8]>> Base64<C, B> {
         self.bytes.as_ref()
     }
 
+    /// Get the number of raw bytes held by this `Base64` instance.
+    pub fn decoded_len(&self) -> usize {
+        self.as_bytes().len()
+    }
+
     /// Encode the bytes contained in this `Base64` instance to unpadded base64.
     pub fn encode(&self) -> String {
         Self::ENGINE.encode(self.as_bytes())
@@ -94,8 +99,77 @@ impl<C: Base64Config> Base64<C> {
 
     /// Parse some base64-encoded data to create a `Base64` instance.
     pub fn parse(encoded: impl AsRef<[u8]>) -> Result<Self, Base64DecodeError> {
-        Self::ENGINE.decode(encoded).map(Self::new).map_err(Base64DecodeError)
+        Self::ENGINE
+            .decode(encoded)
+            .map(Self::new)
+            .map_err(|e| Base64DecodeError(DecodeErrorKind::Decode(e)))
+    }
+
+    /// Parse some base64-encoded data to create a `Base64` instance, expecting the decoded data to
+    /// be exactly `expected_len` bytes long.
+    ///
+    /// The length is checked before decoding, so no allocation happens for input of the wrong
+    /// length.
+    pub fn parse_exact(
+        encoded: impl AsRef<[u8]>,
+        expected_len: usize,
+    ) -> Result<Self, Base64DecodeError> {
+        let encoded = encoded.as_ref();
+        check_decoded_len(encoded, expected_len)?;
+
+        Self::parse(encoded)
+    }
+
+    /// Decode some base64-encoded data by appending it to the given buffer.
+    ///
+    /// This allows to reuse the same buffer to decode many values, instead of allocating a new
+    /// `Vec<u8>` for each of them like [`Base64::parse`]. On error, the content of `buf` is
+    /// unspecified.
+    pub fn parse_into(
+        encoded: impl AsRef<[u8]>,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), Base64DecodeError> {
+        Self::ENGINE
+            .decode_vec(encoded, buf)
+            .map_err(|e| Base64DecodeError(DecodeErrorKind::Decode(e)))
+    }
+}
+
+impl<C: Base64Config, const N: usize> Base64<C, [u8; N]> {
+    /// Parse some base64-encoded data of exactly `N` decoded bytes to create a `Base64` instance.
+    ///
+    /// Unlike [`Base64::parse`], this doesn't allocate. This is useful for data of a known length,
+    /// like hashes, keys or signatures.
+    pub fn parse_array(encoded: impl AsRef<[u8]>) -> Result<Self, Base64DecodeError> {
+        let encoded = encoded.as_ref();
+        check_decoded_len(encoded, N)?;
+
+        let mut bytes = [0; N];
+        // The length check above makes sure that the decoded data fits in `bytes`.
+        let found = Self::ENGINE
+            .decode_slice_unchecked(encoded, &mut bytes)
+            .map_err(|e| Base64DecodeError(DecodeErrorKind::Decode(e)))?;
+        if found != N {
+            return Err(Base64DecodeError(DecodeErrorKind::InvalidLength { expected: N, found }));
+        }
+
+        Ok(Self::new(bytes))
+    }
+}
+
+/// Check that the given unpadded base64-encoded data decodes to `expected_len` bytes.
+fn check_decoded_len(encoded: &[u8], expected_len: usize) -> Result<(), Base64DecodeError> {
+    // Without padding, every 4 characters encode 3 bytes and trailing characters encode the
+    // remaining whole bytes.
+    let found = encoded.len() / 4 * 3 + (encoded.len() % 4).saturating_sub(1);
+    if found != expected_len {
+        return Err(Base64DecodeError(DecodeErrorKind::InvalidLength {
+            expected: expected_len,
+            found,
+        }));
     }
+
+    Ok(())
 }
 
 impl<C: Base64Config, B: AsRef<[u8]>> fmt::Debug for Base64<C, B> {
@@ -120,6 +194,18 @@ impl<'de, C: Base64Config> Deserialize<'de> for Base64<C> {
     }
 }
 
+/// Deserializes data of a known length without allocating, unless the string contains escape
+/// sequences.
+impl<'de, C: Base64Config, const N: usize> Deserialize<'de> for Base64<C, [u8; N]> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let encoded = super::deserialize_cow_str(deserializer)?;
+        Self::parse_array(&*encoded).map_err(de::Error::custom)
+    }
+}
+
 impl<C: Base64Config, B: AsRef<[u8]>> Serialize for Base64<C, B> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -131,17 +217,35 @@ impl<C: Base64Config, B: AsRef<[u8]>> Serialize for Base64<C, B> {
 
 /// An error that occurred while decoding a base64 string.
 #[derive(Clone)]
-pub struct Base64DecodeError(base64::DecodeError);
+pub struct Base64DecodeError(DecodeErrorKind);
+
+#[derive(Clone)]
+enum DecodeErrorKind {
+    Decode(base64::DecodeError),
+    InvalidLength { expected: usize, found: usize },
+}
 
 impl fmt::Debug for Base64DecodeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
+        match &self.0 {
+            DecodeErrorKind::Decode(error) => error.fmt(f),
+            DecodeErrorKind::InvalidLength { expected, found } => f
+                .debug_struct("InvalidLength")
+                .field("expected", expected)
+                .field("found", found)
+                .finish(),
+        }
     }
 }
 
 impl fmt::Display for Base64DecodeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
+        match &self.0 {
+            DecodeErrorKind::Decode(error) => error.fmt(f),
+            DecodeErrorKind::InvalidLength { expected, found } => {
+                write!(f, "expected {expected} decoded bytes, found {found}")
+            }
+        }
     }
 }
 
@@ -149,7 +253,7 @@ impl std::error::Error for Base64DecodeError {}
 
 #[cfg(test)]
 mod tests {
-    use super::{Base64, Standard};
+    use super::{Base64, Standard, UrlSafe};
 
     #[test]
     fn slightly_malformed_base64() {
@@ -157,4 +261,110 @@ mod tests {
             MHverEUn0ztuIsvVxX89JXX2pvdTsOBbLQx+4TVL02l4Cp5wPCm";
         Base64::<Standard>::parse(INPUT).unwrap();
     }
+
+    #[test]
+    fn optional_field() {
+        use serde::{Deserialize, Serialize};
+        use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
+
+        #[derive(Deserialize, Serialize)]
+        struct OptionalBase64 {
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            data: Option<Base64>,
+        }
+
+        let some: OptionalBase64 = from_json_value(json!({ "data": "AAEC" })).unwrap();
+        assert_eq!(some.data.as_ref().unwrap().as_bytes(), [0, 1, 2]);
+        assert_eq!(to_json_value(some).unwrap(), json!({ "data": "AAEC" }));
+
+        let absent: OptionalBase64 = from_json_value(json!({})).unwrap();
+        assert!(absent.data.is_none());
+        assert_eq!(to_json_value(absent).unwrap(), json!({}));
+
+        let null: OptionalBase64 = from_json_value(json!({ "data": null })).unwrap();
+        assert!(null.data.is_none());
+    }
+
+    #[test]
+    fn url_safe_encode() {
+        let b64 = Base64::<UrlSafe>::new(vec![0xFB, 0xFF, 0xBF]);
+        assert_eq!(b64.encode(), "-_-_");
+
+        let b64 = Base64::<Standard>::new(vec![0xFB, 0xFF, 0xBF]);
+        assert_eq!(b64.encode(), "+/+/");
+    }
+
+    #[test]
+    fn parse_into_buffer() {
+        let mut buf = Vec::with_capacity(16);
+        let capacity = buf.capacity();
+
+        Base64::<Standard>::parse_into("+/8", &mut buf).unwrap();
+        assert_eq!(buf, Base64::<Standard>::parse("+/8").unwrap().as_bytes());
+
+        buf.clear();
+        Base64::<Standard>::parse_into("AAEC", &mut buf).unwrap();
+        assert_eq!(buf, [0, 1, 2]);
+
+        Base64::<Standard>::parse_into("Aw", &mut buf).unwrap();
+        assert_eq!(buf, [0, 1, 2, 3]);
+        assert_eq!(buf.capacity(), capacity);
+
+        Base64::<UrlSafe>::parse_into("+/8", &mut buf).unwrap_err();
+    }
+
+    #[test]
+    fn url_safe_roundtrip() {
+        let b64 = Base64::<UrlSafe>::new(vec![0xFB, 0xFF]);
+        let json = serde_json::to_string(&b64).unwrap();
+        assert_eq!(json, r#""-_8""#);
+
+        let parsed: Base64<UrlSafe> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.as_bytes(), &[0xFB, 0xFF]);
+        assert!(serde_json::from_str::<Base64<UrlSafe>>(r#""+/8""#).is_err());
+    }
+
+    #[test]
+    fn url_safe_parse() {
+        let b64 = Base64::<UrlSafe>::parse("-_-_").unwrap();
+        assert_eq!(b64.as_bytes(), &[0xFB, 0xFF, 0xBF]);
+
+        Base64::<UrlSafe>::parse("+/+/").unwrap_err();
+    }
+
+    #[test]
+    fn parse_exact_length() {
+        const SIGNATURE: &str = "K8280/U9SSy9IVtjBuVeLr+HpOB4BQFWbg+UZaADMt\
+            TdGYI7Geitb76LTrr5QV/7Xg4ahLwYGYZzuHGZKM5ZAQ";
+
+        let b64 = Base64::<Standard>::parse_exact(SIGNATURE, 64).unwrap();
+        assert_eq!(b64.decoded_len(), 64);
+
+        Base64::<Standard>::parse_exact(&SIGNATURE[..SIGNATURE.len() - 4], 64).unwrap_err();
+    }
+
+    #[test]
+    fn parse_array() {
+        const SIGNATURE: &str = "K8280/U9SSy9IVtjBuVeLr+HpOB4BQFWbg+UZaADMt\
+            TdGYI7Geitb76LTrr5QV/7Xg4ahLwYGYZzuHGZKM5ZAQ";
+
+        let b64 = Base64::<Standard, [u8; 64]>::parse_array(SIGNATURE).unwrap();
+        assert_eq!(b64.as_bytes(), Base64::<Standard>::parse(SIGNATURE).unwrap().as_bytes());
+
+        Base64::<Standard, [u8; 63]>::parse_array(SIGNATURE).unwrap_err();
+        Base64::<Standard, [u8; 3]>::parse_array("+/+!").unwrap_err();
+        Base64::<UrlSafe, [u8; 3]>::parse_array("+/+/").unwrap_err();
+    }
+
+    #[test]
+    fn deserialize_array() {
+        let b64: Base64<UrlSafe, [u8; 3]> = serde_json::from_str(r#""-_-_""#).unwrap();
+        assert_eq!(b64.as_bytes(), &[0xFB, 0xFF, 0xBF]);
+
+        // Escaped strings are supported too.
+        let b64: Base64<UrlSafe, [u8; 3]> = serde_json::from_str(r#""-_\u002d_""#).unwrap();
+        assert_eq!(b64.as_bytes(), &[0xFB, 0xFF, 0xBF]);
+
+        serde_json::from_str::<Base64<UrlSafe, [u8; 2]>>(r#""-_-_""#).unwrap_err();
+    }
 }
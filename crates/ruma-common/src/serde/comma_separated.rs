@@ -0,0 +1,69 @@
+//! De-/serialization functions to and from a single comma-separated string.
+//!
+//! This is used for query parameters that contain a list, when the server expects
+//! `field=val1,val2` instead of `field=val1&field=val2`.
+
+use std::{fmt, str::FromStr};
+
+use serde::{de, Deserializer, Serializer};
+
+/// Serialize the given list of values as a single string, with the values separated by commas.
+pub fn serialize<T, S>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: fmt::Display,
+    S: Serializer,
+{
+    let joined = values.iter().map(ToString::to_string).collect::<Vec<_>>().join(",");
+    serializer.serialize_str(&joined)
+}
+
+/// Deserialize a list of values from a single string, with the values separated by commas.
+///
+/// An empty string results in an empty list.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+    D: Deserializer<'de>,
+{
+    let s = super::deserialize_cow_str(deserializer)?;
+    if s.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    s.split(',').map(|value| value.parse().map_err(de::Error::custom)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Query {
+        #[serde(default, with = "super")]
+        values: Vec<u8>,
+    }
+
+    #[test]
+    fn serialize() {
+        let query = Query { values: vec![1, 2, 3] };
+        assert_eq!(serde_html_form::to_string(&query).unwrap(), "values=1%2C2%2C3");
+
+        let query = Query { values: vec![] };
+        assert_eq!(serde_html_form::to_string(&query).unwrap(), "values=");
+    }
+
+    #[test]
+    fn deserialize() {
+        assert_eq!(
+            serde_html_form::from_str::<Query>("values=1,2,3").unwrap(),
+            Query { values: vec![1, 2, 3] }
+        );
+        assert_eq!(
+            serde_html_form::from_str::<Query>("values=").unwrap(),
+            Query { values: vec![] }
+        );
+        assert_eq!(serde_html_form::from_str::<Query>("").unwrap(), Query { values: vec![] });
+        serde_html_form::from_str::<Query>("values=1,a").unwrap_err();
+    }
+}
@@ -1,5 +1,6 @@
 use std::{
     clone::Clone,
+    collections::BTreeSet,
     fmt::{self, Debug},
     marker::PhantomData,
     mem,
@@ -9,7 +10,9 @@ use serde::{
     de::{self, Deserialize, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, Visitor},
     ser::{Serialize, Serializer},
 };
-use serde_json::value::{to_raw_value as to_raw_json_value, RawValue as RawJsonValue};
+use serde_json::value::{
+    to_raw_value as to_raw_json_value, RawValue as RawJsonValue, Value as JsonValue,
+};
 
 /// A wrapper around `Box<RawValue>`, to be used in place of any type in the Matrix endpoint
 /// definition to allow request and response types to contain that said type represented by
@@ -61,6 +64,13 @@ impl<T> Raw<T> {
         Self { json, _ev: PhantomData }
     }
 
+    /// Create a `Raw` from an already-parsed JSON value.
+    ///
+    /// Note that this doesn't check that the JSON value can be deserialized to a `T`.
+    pub fn from_value(value: &JsonValue) -> serde_json::Result<Self> {
+        to_raw_json_value(value).map(Self::from_json)
+    }
+
     /// Convert an owned `String` of JSON data to `Raw<T>`.
     ///
     /// This function is equivalent to `serde_json::from_str::<Raw<T>>` except that an allocation
@@ -164,7 +174,7 @@ impl<T> Raw<T> {
                 let mut res = None;
                 while let Some(is_right_field) = map.next_key_seed(Field(self.field_name))? {
                     if is_right_field {
-                        res = Some(map.next_value()?);
+                        res = map.next_value()?;
                     } else {
                         map.next_value::<IgnoredAny>()?;
                     }
@@ -186,6 +196,83 @@ impl<T> Raw<T> {
         serde_json::from_str(self.json.get())
     }
 
+    /// Try to deserialize the JSON object as the expected type, failing if the object has duplicate
+    /// keys.
+    ///
+    /// [`Raw::deserialize`] keeps the last value of a duplicate key, which happens silently when
+    /// unknown fields are collected in a map, like the fields of custom content. Duplicate keys
+    /// can be a sign of a malformed or malicious payload.
+    ///
+    /// Only the top-level keys are checked. Returns an error if the JSON is not an object.
+    pub fn deserialize_strict<'a>(&'a self) -> serde_json::Result<T>
+    where
+        T: Deserialize<'a>,
+    {
+        struct UniqueKeysVisitor;
+
+        impl<'de> Visitor<'de> for UniqueKeysVisitor {
+            type Value = ();
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a JSON object")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut keys = BTreeSet::new();
+                while let Some(key) = map.next_key::<String>()? {
+                    if keys.contains(&key) {
+                        return Err(de::Error::custom(format!("duplicate key `{key}`")));
+                    }
+
+                    map.next_value::<IgnoredAny>()?;
+                    keys.insert(key);
+                }
+
+                Ok(())
+            }
+        }
+
+        let mut deserializer = serde_json::Deserializer::from_str(self.json().get());
+        deserializer.deserialize_map(UniqueKeysVisitor)?;
+
+        self.deserialize()
+    }
+
+    /// Try to deserialize the JSON as the expected type, failing if the JSON contains fields that
+    /// are ignored by the type.
+    ///
+    /// [`Raw::deserialize`] ignores unknown fields, to be forwards-compatible with new versions of
+    /// the specification. This is useful in tests, to catch deviations of the JSON from the type,
+    /// like the content of known events with fields that are not part of the specification.
+    ///
+    /// The content of events, that is deserialized separately from a `RawValue`, is checked too.
+    /// Fields that are collected in a map, like the fields of custom content, are not ignored so
+    /// they are always accepted. Unknown fields of types using `#[serde(flatten)]` cannot be
+    /// detected either.
+    #[cfg(feature = "strict-deserialize")]
+    pub fn deserialize_deny_unknown_fields<'a>(&'a self) -> serde_json::Result<T>
+    where
+        T: Deserialize<'a>,
+    {
+        let _guard = DenyUnknownFieldsGuard::new();
+        from_json_str_deny_unknown_fields(self.json.get())
+    }
+
+    /// Try to deserialize the JSON as the expected type, and return it along with the full JSON
+    /// value.
+    ///
+    /// This is useful to re-emit the original JSON, including the fields that are unknown to `T`,
+    /// after looking at the typed value.
+    pub fn deserialize_with_raw<'a>(&'a self) -> serde_json::Result<(T, JsonValue)>
+    where
+        T: Deserialize<'a>,
+    {
+        Ok((self.deserialize()?, serde_json::from_str(self.json.get())?))
+    }
+
     /// Try to deserialize the JSON as a custom type.
     pub fn deserialize_as<'a, U>(&'a self) -> serde_json::Result<U>
     where
@@ -196,19 +283,76 @@ impl<T> Raw<T> {
 
     /// Turns `Raw<T>` into `Raw<U>` without changing the underlying JSON.
     ///
-    /// This is useful for turning raw specific event types into raw event enum types.
+    /// This is useful for turning raw specific event types into raw event enum types, or raw event
+    /// enum types into a specific event type after looking at the `type` field.
+    ///
+    /// The cast is unchecked: the JSON is only validated against `U` when it is deserialized.
     pub fn cast<U>(self) -> Raw<U> {
         Raw::from_json(self.into_json())
     }
 
     /// Turns `&Raw<T>` into `&Raw<U>` without changing the underlying JSON.
     ///
-    /// This is useful for turning raw specific event types into raw event enum types.
+    /// This is useful for turning raw specific event types into raw event enum types, or raw event
+    /// enum types into a specific event type after looking at the `type` field.
+    ///
+    /// The cast is unchecked: the JSON is only validated against `U` when it is deserialized.
     pub fn cast_ref<U>(&self) -> &Raw<U> {
         unsafe { mem::transmute(self) }
     }
 }
 
+#[cfg(feature = "strict-deserialize")]
+thread_local! {
+    static DENY_UNKNOWN_FIELDS: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Whether unknown fields are currently denied, because we are inside
+/// [`Raw::deserialize_deny_unknown_fields`].
+#[cfg(feature = "strict-deserialize")]
+pub(crate) fn denying_unknown_fields() -> bool {
+    DENY_UNKNOWN_FIELDS.with(|deny| deny.get())
+}
+
+/// Denies unknown fields on the current thread until it is dropped.
+#[cfg(feature = "strict-deserialize")]
+struct DenyUnknownFieldsGuard {
+    previous: bool,
+}
+
+#[cfg(feature = "strict-deserialize")]
+impl DenyUnknownFieldsGuard {
+    fn new() -> Self {
+        Self { previous: DENY_UNKNOWN_FIELDS.with(|deny| deny.replace(true)) }
+    }
+}
+
+#[cfg(feature = "strict-deserialize")]
+impl Drop for DenyUnknownFieldsGuard {
+    fn drop(&mut self) {
+        DENY_UNKNOWN_FIELDS.with(|deny| deny.set(self.previous));
+    }
+}
+
+/// Deserialize a `T` from the given JSON string, failing if it contains fields that are ignored
+/// by `T`.
+#[cfg(feature = "strict-deserialize")]
+pub(crate) fn from_json_str_deny_unknown_fields<'a, T>(json: &'a str) -> serde_json::Result<T>
+where
+    T: Deserialize<'a>,
+{
+    let mut unknown_field = None;
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let value = serde_ignored::deserialize(&mut deserializer, |path| {
+        unknown_field.get_or_insert_with(|| path.to_string());
+    })?;
+
+    match unknown_field {
+        Some(path) => Err(de::Error::custom(format!("unknown field `{path}`"))),
+        None => Ok(value),
+    }
+}
+
 impl<T> Clone for Raw<T> {
     fn clone(&self) -> Self {
         Self::from_json(self.json.clone())
@@ -243,7 +387,7 @@ impl<T> Serialize for Raw<T> {
 #[cfg(test)]
 mod tests {
     use serde::Deserialize;
-    use serde_json::{from_str as from_json_str, value::RawValue as RawJsonValue};
+    use serde_json::{from_str as from_json_str, json, value::RawValue as RawJsonValue};
 
     use super::Raw;
 
@@ -255,7 +399,7 @@ mod tests {
             b: Vec<&'a str>,
         }
 
-        const OBJ: &str = r#"{ "a": { "b": [  "c"] }, "z": 5 }"#;
+        const OBJ: &str = r#"{ "a": { "b": [  "c"] }, "n": null, "z": 5 }"#;
         let raw: Raw<()> = from_json_str(OBJ)?;
 
         assert_eq!(raw.get_field::<u8>("z")?, Some(5));
@@ -263,8 +407,59 @@ mod tests {
         assert_eq!(raw.get_field::<A<'_>>("a")?, Some(A { b: vec!["c"] }));
 
         assert_eq!(raw.get_field::<u8>("b")?, None);
+        assert_eq!(raw.get_field::<u8>("n")?, None);
         raw.get_field::<u8>("a").unwrap_err();
 
         Ok(())
     }
+
+    #[test]
+    fn from_value() -> serde_json::Result<()> {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct A {
+            b: Vec<String>,
+        }
+
+        let raw = Raw::<A>::from_value(&json!({ "b": ["c"] }))?;
+        assert_eq!(raw.json().get(), r#"{"b":["c"]}"#);
+        assert_eq!(raw.deserialize()?, A { b: vec!["c".to_owned()] });
+
+        let raw = Raw::<A>::from_value(&json!({ "b": "c" }))?;
+        raw.deserialize().unwrap_err();
+
+        Ok(())
+    }
+
+    #[test]
+    fn json_is_verbatim() {
+        #[derive(Deserialize)]
+        struct A {}
+
+        const OBJ: &str = r#"{ "b" :"c",  "a": [1, 2 ], "b": null }"#;
+        let raw: Raw<A> = from_json_str(OBJ).unwrap();
+        assert_eq!(raw.json().get().as_bytes(), OBJ.as_bytes());
+
+        // Deserializing doesn't change the stored JSON.
+        raw.deserialize().unwrap();
+        assert_eq!(raw.into_json().get(), OBJ);
+    }
+
+    #[test]
+    fn deserialize_strict() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct A {
+            b: String,
+        }
+
+        let raw: Raw<A> = from_json_str(r#"{ "b": "c", "d": 1 }"#).unwrap();
+        assert_eq!(raw.deserialize_strict().unwrap(), A { b: "c".to_owned() });
+
+        let raw: Raw<A> = from_json_str(r#"{ "b": "c", "d": 1, "d": 2 }"#).unwrap();
+        raw.deserialize().unwrap();
+        let err = raw.deserialize_strict().unwrap_err();
+        assert!(err.to_string().contains("duplicate key `d`"));
+
+        let raw: Raw<A> = from_json_str(r#"["c"]"#).unwrap();
+        raw.deserialize_strict().unwrap_err();
+    }
 }
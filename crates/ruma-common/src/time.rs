@@ -44,6 +44,21 @@ impl MilliSecondsSinceUnixEpoch {
     pub fn as_secs(&self) -> UInt {
         self.0 / uint!(1000)
     }
+
+    /// Subtracts the given duration from `self`.
+    ///
+    /// Returns `None` if the result would be before the unix epoch.
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        let millis = UInt::try_from(duration.as_millis()).ok()?;
+        self.0.checked_sub(millis).map(Self)
+    }
+
+    /// Get the duration between `earlier` and `self`.
+    ///
+    /// Returns `None` if `earlier` is later than `self`.
+    pub fn elapsed_since(&self, earlier: MilliSecondsSinceUnixEpoch) -> Option<Duration> {
+        self.0.checked_sub(earlier.0).map(|millis| Duration::from_millis(millis.into()))
+    }
 }
 
 impl fmt::Debug for MilliSecondsSinceUnixEpoch {
@@ -108,7 +123,7 @@ fn f64_to_uint(val: f64) -> UInt {
 mod tests {
     use std::time::{Duration, UNIX_EPOCH};
 
-    use js_int::uint;
+    use js_int::{uint, UInt};
     use serde::{Deserialize, Serialize};
     use serde_json::json;
 
@@ -139,4 +154,33 @@ mod tests {
 
         assert_eq!(serde_json::to_value(request).unwrap(), json!({ "millis": 2000, "secs": 0 }));
     }
+
+    #[test]
+    fn system_time_round_trip() {
+        let system_time = UNIX_EPOCH + Duration::from_millis(1_680_000_000_123);
+
+        let millis = MilliSecondsSinceUnixEpoch::from_system_time(system_time).unwrap();
+        assert_eq!(millis, MilliSecondsSinceUnixEpoch(UInt::new(1_680_000_000_123).unwrap()));
+        assert_eq!(millis.to_system_time(), Some(system_time));
+
+        assert_eq!(
+            MilliSecondsSinceUnixEpoch::from_system_time(UNIX_EPOCH - Duration::from_secs(1)),
+            None
+        );
+        assert!(MilliSecondsSinceUnixEpoch::now() >= millis);
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let earlier = MilliSecondsSinceUnixEpoch(uint!(1000));
+        let later = MilliSecondsSinceUnixEpoch(uint!(61_000));
+
+        assert_eq!(later.elapsed_since(earlier), Some(Duration::from_secs(60)));
+        assert_eq!(later.elapsed_since(later), Some(Duration::ZERO));
+        assert_eq!(earlier.elapsed_since(later), None);
+
+        assert_eq!(later.checked_sub(Duration::from_secs(60)), Some(earlier));
+        assert_eq!(earlier.checked_sub(Duration::from_secs(60)), None);
+        assert_eq!(earlier.checked_sub(Duration::MAX), None);
+    }
 }
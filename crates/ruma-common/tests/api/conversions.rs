@@ -1,10 +1,12 @@
 #![allow(clippy::exhaustive_structs)]
 
+use assert_matches::assert_matches;
 use http::header::CONTENT_TYPE;
 use ruma_common::{
     api::{
-        request, response, IncomingRequest as _, MatrixVersion, Metadata, OutgoingRequest as _,
-        OutgoingRequestAppserviceExt, SendAccessToken,
+        error::{DeserializationError, FromHttpResponseError, HeaderDeserializationError},
+        request, response, IncomingRequest as _, IncomingResponse as _, MatrixVersion, Metadata,
+        OutgoingRequest as _, OutgoingRequestAppserviceExt, SendAccessToken,
     },
     metadata, user_id, OwnedUserId,
 };
@@ -40,7 +42,7 @@ pub struct Request {
 }
 
 /// Response type for the `my_endpoint` endpoint.
-#[response]
+#[response(check_content_type)]
 pub struct Response {
     pub hello: String,
 
@@ -80,6 +82,32 @@ fn request_serde() {
     assert_eq!(req.user, req2.user);
 }
 
+#[test]
+fn estimated_body_len() {
+    let req = Request {
+        hello: "hi".to_owned(),
+        world: "test".to_owned(),
+        q1: "query_param_special_chars %/&@!".to_owned(),
+        q2: 55,
+        bar: "barVal".to_owned(),
+        user: user_id!("@bazme:ruma.io").to_owned(),
+    };
+    assert_eq!(req.estimated_body_len(), None);
+
+    let req = raw_body::Request { file: b"file contents".to_vec() };
+    let http_req = req
+        .clone()
+        .try_into_http_request::<Vec<u8>>(
+            "https://homeserver.tld",
+            SendAccessToken::None,
+            &[MatrixVersion::V1_1],
+        )
+        .unwrap();
+
+    assert_eq!(req.estimated_body_len(), Some(http_req.body().len()));
+    assert_eq!(req.estimated_body_len(), Some(13));
+}
+
 #[test]
 fn invalid_uri_should_not_panic() {
     let req = Request {
@@ -128,6 +156,37 @@ fn request_with_user_id_serde() {
     );
 }
 
+#[test]
+fn response_content_type() {
+    let http_res = http::Response::builder()
+        .header(CONTENT_TYPE, "application/json; charset=utf-8")
+        .body(br#"{"hello":"hi"}"# as &[u8])
+        .unwrap();
+    let res = Response::try_from_http_response(http_res).unwrap();
+    assert_eq!(res.hello, "hi");
+    assert_eq!(res.world, "application/json; charset=utf-8");
+
+    let http_res = http::Response::builder()
+        .header(CONTENT_TYPE, "text/html")
+        .body(b"<html><body>Bad Gateway</body></html>" as &[u8])
+        .unwrap();
+    let content_type = assert_matches!(
+        Response::try_from_http_response(http_res),
+        Err(FromHttpResponseError::Deserialization(DeserializationError::Header(
+            HeaderDeserializationError::UnexpectedContentType(content_type)
+        ))) => content_type
+    );
+    assert_eq!(content_type, "text/html");
+
+    // Without `check_content_type`, any `Content-Type` is accepted.
+    let http_res = http::Response::builder()
+        .header(CONTENT_TYPE, "text/plain")
+        .body(br#"{"hello":"hi"}"# as &[u8])
+        .unwrap();
+    let res = without_query::Response::try_from_http_response(http_res).unwrap();
+    assert_eq!(res.hello, "hi");
+}
+
 mod without_query {
     use http::header::CONTENT_TYPE;
     use ruma_common::{
@@ -198,3 +257,30 @@ mod without_query {
         assert_eq!(query, "user_id=%40_virtual_%3Aruma.io");
     }
 }
+
+mod raw_body {
+    use ruma_common::{
+        api::{request, response, Metadata},
+        metadata,
+    };
+
+    const METADATA: Metadata = metadata! {
+        method: PUT,
+        rate_limited: false,
+        authentication: None,
+        history: {
+            unstable => "/_matrix/foo/upload",
+        }
+    };
+
+    /// Request type for the `upload` endpoint.
+    #[request]
+    pub struct Request {
+        #[ruma_api(raw_body)]
+        pub file: Vec<u8>,
+    }
+
+    /// Response type for the `upload` endpoint.
+    #[response]
+    pub struct Response {}
+}
@@ -0,0 +1,50 @@
+#![allow(clippy::exhaustive_structs)]
+
+use assert_matches::assert_matches;
+use ruma_common::{
+    api::{
+        error::IntoHttpError, request, response, MatrixVersion, Metadata, OutgoingRequest as _,
+        SendAccessToken,
+    },
+    metadata,
+};
+
+const METADATA: Metadata = metadata! {
+    method: POST,
+    rate_limited: false,
+    authentication: None,
+    history: {
+        unstable => "/_matrix/my/endpoint",
+    }
+};
+
+/// Request type for the `max_body` endpoint.
+#[request(max_body = 32)]
+pub struct Request {
+    pub hello: String,
+}
+
+/// Response type for the `max_body` endpoint.
+#[response]
+pub struct Response {}
+
+fn try_into_http_request(hello: &str) -> Result<http::Request<Vec<u8>>, IntoHttpError> {
+    Request { hello: hello.to_owned() }.try_into_http_request(
+        "https://homeserver.tld",
+        SendAccessToken::None,
+        &[MatrixVersion::V1_1],
+    )
+}
+
+#[test]
+fn body_within_limit() {
+    // `{"hello":""}` is 12 bytes long.
+    let http_req = try_into_http_request(&"a".repeat(20)).unwrap();
+    assert_eq!(http_req.body().len(), 32);
+}
+
+#[test]
+fn body_exceeding_limit() {
+    let err = try_into_http_request(&"a".repeat(21)).unwrap_err();
+    assert_matches!(err, IntoHttpError::BodyTooLarge { len: 33, max: 32 });
+}
@@ -3,7 +3,9 @@
 mod conversions;
 mod header_override;
 mod manual_endpoint_impl;
+mod max_body;
 mod no_fields;
 mod optional_headers;
+mod query_style;
 mod ruma_api;
 mod ruma_api_macros;
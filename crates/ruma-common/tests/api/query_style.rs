@@ -0,0 +1,108 @@
+#![allow(clippy::exhaustive_structs)]
+
+use ruma_common::{
+    api::{IncomingRequest as _, MatrixVersion, OutgoingRequest as _, SendAccessToken},
+    server_name, OwnedServerName,
+};
+
+mod repeat {
+    use ruma_common::{
+        api::{request, response, Metadata},
+        metadata, OwnedServerName,
+    };
+
+    const METADATA: Metadata = metadata! {
+        method: GET,
+        rate_limited: false,
+        authentication: None,
+        history: {
+            unstable => "/_matrix/foo/repeat",
+        }
+    };
+
+    /// Request type for the `repeat` endpoint.
+    #[request]
+    pub struct Request {
+        #[ruma_api(query)]
+        #[serde(default, skip_serializing_if = "<[_]>::is_empty")]
+        pub server_name: Vec<OwnedServerName>,
+    }
+
+    /// Response type for the `repeat` endpoint.
+    #[response]
+    pub struct Response {}
+}
+
+mod comma {
+    use ruma_common::{
+        api::{request, response, Metadata},
+        metadata, OwnedServerName,
+    };
+
+    const METADATA: Metadata = metadata! {
+        method: GET,
+        rate_limited: false,
+        authentication: None,
+        history: {
+            unstable => "/_matrix/foo/comma",
+        }
+    };
+
+    /// Request type for the `comma` endpoint.
+    #[request]
+    pub struct Request {
+        #[ruma_api(query, style = "comma")]
+        #[serde(default, skip_serializing_if = "<[_]>::is_empty")]
+        pub server_name: Vec<OwnedServerName>,
+    }
+
+    /// Response type for the `comma` endpoint.
+    #[response]
+    pub struct Response {}
+}
+
+fn servers() -> Vec<OwnedServerName> {
+    vec![server_name!("example.org").to_owned(), server_name!("example.com").to_owned()]
+}
+
+#[test]
+fn repeat_query_style() {
+    let http_req = repeat::Request { server_name: servers() }
+        .try_into_http_request::<Vec<u8>>(
+            "https://homeserver.tld",
+            SendAccessToken::None,
+            &[MatrixVersion::V1_1],
+        )
+        .unwrap();
+    assert_eq!(http_req.uri().query(), Some("server_name=example.org&server_name=example.com"));
+
+    let req = repeat::Request::try_from_http_request(http_req, &[] as &[String]).unwrap();
+    assert_eq!(req.server_name, servers());
+}
+
+#[test]
+fn comma_query_style() {
+    let http_req = comma::Request { server_name: servers() }
+        .try_into_http_request::<Vec<u8>>(
+            "https://homeserver.tld",
+            SendAccessToken::None,
+            &[MatrixVersion::V1_1],
+        )
+        .unwrap();
+    assert_eq!(http_req.uri().query(), Some("server_name=example.org%2Cexample.com"));
+
+    let req = comma::Request::try_from_http_request(http_req, &[] as &[String]).unwrap();
+    assert_eq!(req.server_name, servers());
+
+    let http_req = comma::Request { server_name: vec![] }
+        .try_into_http_request::<Vec<u8>>(
+            "https://homeserver.tld",
+            SendAccessToken::None,
+            &[MatrixVersion::V1_1],
+        )
+        .unwrap();
+    assert_eq!(http_req.uri().query(), None);
+
+    let req = comma::Request::try_from_http_request(http_req, &[] as &[String]).unwrap();
+    assert_eq!(req.server_name, Vec::<OwnedServerName>::new());
+}
@@ -21,6 +21,7 @@ mod relations;
 mod room_message;
 mod state_event;
 mod sticker;
+mod strict_deserialize;
 mod stripped;
 mod to_device;
 mod video;
@@ -1,6 +1,6 @@
 #![cfg(feature = "unstable-msc3381")]
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use assert_matches::assert_matches;
 use js_int::uint;
@@ -9,16 +9,19 @@ use ruma_common::{
     events::{
         message::TextContentBlock,
         poll::{
+            compile_poll_results,
             end::PollEndEventContent,
-            response::PollResponseEventContent,
+            response::{PollResponseEventContent, SelectionsContentBlock},
+            responses_before_end,
             start::{
                 PollAnswer, PollAnswers, PollAnswersError, PollContentBlock, PollKind,
                 PollStartEventContent,
             },
         },
         relation::Reference,
-        AnyMessageLikeEvent, MessageLikeEvent,
+        AnyMessageLikeEvent, EventContent, MessageLikeEvent, StaticEventContent,
     },
+    user_id, MilliSecondsSinceUnixEpoch,
 };
 use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
@@ -212,6 +215,39 @@ fn response_content_serialization() {
     );
 }
 
+#[test]
+fn response_single_and_empty_content_serialization() {
+    let event_content = PollResponseEventContent::single(
+        "my-answer".to_owned(),
+        event_id!("$related_event:notareal.hs").to_owned(),
+    );
+
+    assert_eq!(
+        to_json_value(&event_content).unwrap(),
+        json!({
+            "org.matrix.msc3381.v2.selections": ["my-answer"],
+            "m.relates_to": {
+                "rel_type": "m.reference",
+                "event_id": "$related_event:notareal.hs",
+            }
+        })
+    );
+
+    let event_content =
+        PollResponseEventContent::empty(event_id!("$related_event:notareal.hs").to_owned());
+
+    assert_eq!(
+        to_json_value(&event_content).unwrap(),
+        json!({
+            "org.matrix.msc3381.v2.selections": [],
+            "m.relates_to": {
+                "rel_type": "m.reference",
+                "event_id": "$related_event:notareal.hs",
+            }
+        })
+    );
+}
+
 #[test]
 fn response_event_serialization() {
     let content = PollResponseEventContent::new(
@@ -264,6 +300,162 @@ fn response_event_deserialization() {
     assert_eq!(event_id, "$related_event:notareal.hs");
 }
 
+#[test]
+fn response_event_type() {
+    let json_data = json!({
+        "content": {
+            "org.matrix.msc3381.v2.selections": ["my-answer"],
+            "m.relates_to": {
+                "rel_type": "m.reference",
+                "event_id": "$related_event:notareal.hs",
+            }
+        },
+        "event_id": "$event:notareal.hs",
+        "origin_server_ts": 134_829_848,
+        "room_id": "!roomid:notareal.hs",
+        "sender": "@user:notareal.hs",
+        "type": "m.poll.response",
+    });
+
+    let event = from_json_value::<AnyMessageLikeEvent>(json_data).unwrap();
+    let message_event = assert_matches!(
+        event,
+        AnyMessageLikeEvent::PollResponse(MessageLikeEvent::Original(message_event))
+            => message_event
+    );
+    assert_eq!(
+        message_event.content.event_type().to_string(),
+        "org.matrix.msc3381.v2.poll.response"
+    );
+}
+
+#[test]
+fn response_matches_type() {
+    assert_eq!(PollResponseEventContent::ALIASES, ["m.poll.response"]);
+    assert!(PollResponseEventContent::matches_type("org.matrix.msc3381.v2.poll.response"));
+    assert!(PollResponseEventContent::matches_type("m.poll.response"));
+    assert!(!PollResponseEventContent::matches_type("m.poll.start"));
+}
+
+#[test]
+fn response_selections_validate() {
+    let selections = SelectionsContentBlock::from(vec![
+        "first-answer".to_owned(),
+        "second-answer".to_owned(),
+        "third-answer".to_owned(),
+    ]);
+
+    assert_eq!(selections.validate(uint!(1)), ["first-answer"]);
+    assert_eq!(selections.validate(uint!(2)), ["first-answer", "second-answer"]);
+    assert_eq!(selections.validate(uint!(5)).len(), 3);
+    assert_eq!(selections.first_valid(), Some("first-answer"));
+
+    let selections = SelectionsContentBlock::from(Vec::new());
+
+    assert!(selections.validate(uint!(1)).is_empty());
+    assert_eq!(selections.first_valid(), None);
+}
+
+#[test]
+fn response_selections_from_str_iter() {
+    let mut selections: SelectionsContentBlock = ["a", "b"].into_iter().collect();
+    assert_eq!(*selections, ["a", "b"]);
+
+    selections.extend(["c".to_owned()]);
+    assert_eq!(*selections, ["a", "b", "c"]);
+}
+
+#[test]
+fn response_selections_contains_answer() {
+    let selections: SelectionsContentBlock =
+        ["first-answer", "second-answer"].into_iter().collect();
+
+    assert!(selections.contains_answer("first-answer"));
+    assert!(selections.contains_answer("second-answer"));
+    assert!(!selections.contains_answer("third-answer"));
+    assert!(!selections.contains_answer("first"));
+}
+
+#[test]
+fn response_selections_validate_against() {
+    let selections = SelectionsContentBlock::from(vec![
+        "first-answer".to_owned(),
+        "bogus-answer".to_owned(),
+        "third-answer".to_owned(),
+    ]);
+    let valid_ids: BTreeSet<_> =
+        ["first-answer".to_owned(), "second-answer".to_owned(), "third-answer".to_owned()].into();
+
+    assert_eq!(selections.validate_against(&valid_ids), ["first-answer", "third-answer"]);
+    assert!(selections.validate_against(&BTreeSet::new()).is_empty());
+}
+
+#[test]
+fn compile_results() {
+    let mut poll = PollContentBlock::new(
+        TextContentBlock::plain("How's the weather?"),
+        vec![
+            PollAnswer::new("not-bad".to_owned(), TextContentBlock::plain("Not bad…")),
+            PollAnswer::new("fine".to_owned(), TextContentBlock::plain("Fine.")),
+            PollAnswer::new("amazing".to_owned(), TextContentBlock::plain("Amazing!")),
+        ]
+        .try_into()
+        .unwrap(),
+    );
+    poll.max_selections = uint!(2);
+
+    let responses = vec![
+        (
+            user_id!("@alice:localhost").to_owned(),
+            MilliSecondsSinceUnixEpoch(uint!(2)),
+            vec!["fine".to_owned(), "amazing".to_owned()].into(),
+        ),
+        (
+            user_id!("@bob:localhost").to_owned(),
+            MilliSecondsSinceUnixEpoch(uint!(1)),
+            vec!["not-bad".to_owned()].into(),
+        ),
+        // Alice changes their vote, this response is the one that counts.
+        (
+            user_id!("@alice:localhost").to_owned(),
+            MilliSecondsSinceUnixEpoch(uint!(3)),
+            vec!["amazing".to_owned(), "amazing".to_owned(), "not-bad".to_owned()].into(),
+        ),
+        // Bob changes their vote, but this response is older than the first one so it is ignored.
+        (
+            user_id!("@bob:localhost").to_owned(),
+            MilliSecondsSinceUnixEpoch(uint!(0)),
+            vec!["fine".to_owned()].into(),
+        ),
+        // Carl's only selection doesn't match an answer.
+        (
+            user_id!("@carl:localhost").to_owned(),
+            MilliSecondsSinceUnixEpoch(uint!(4)),
+            vec!["terrible".to_owned()].into(),
+        ),
+    ];
+
+    let results = compile_poll_results(&poll, responses);
+    assert_eq!(results.len(), 3);
+    assert_eq!(results["not-bad"], 1);
+    assert_eq!(results["fine"], 0);
+    assert_eq!(results["amazing"], 1);
+}
+
+#[test]
+fn filter_responses_before_end() {
+    let responses = vec![
+        (MilliSecondsSinceUnixEpoch(uint!(1)), "before"),
+        (MilliSecondsSinceUnixEpoch(uint!(5)), "at-end"),
+        (MilliSecondsSinceUnixEpoch(uint!(6)), "after"),
+        (MilliSecondsSinceUnixEpoch(uint!(3)), "late-before"),
+    ];
+
+    let filtered: Vec<_> =
+        responses_before_end(MilliSecondsSinceUnixEpoch(uint!(5)), responses).collect();
+    assert_eq!(filtered, ["before", "at-end", "late-before"]);
+}
+
 #[test]
 fn end_content_serialization() {
     let event_content = PollEndEventContent::with_plain_text(
@@ -1,12 +1,13 @@
 use assert_matches::assert_matches;
 use js_int::uint;
 use ruma_common::{
+    event_id,
     events::{
         room::redaction::{RoomRedactionEvent, RoomRedactionEventContent},
-        AnyMessageLikeEvent,
+        AnyMessageLikeEvent, MessageLikeEventType,
     },
-    serde::CanBeEmpty,
-    MilliSecondsSinceUnixEpoch,
+    serde::{CanBeEmpty, Raw},
+    user_id, MilliSecondsSinceUnixEpoch, RoomVersionId,
 };
 use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
@@ -22,6 +23,16 @@ fn serialize_redaction_content() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn serialize_empty_redaction_content() {
+    let content = RoomRedactionEventContent::new();
+
+    let actual = to_json_value(content).unwrap();
+    let expected = json!({});
+
+    assert_eq!(actual, expected);
+}
+
 #[test]
 fn deserialize_redaction() {
     let json_data = json!({
@@ -43,8 +54,187 @@ fn deserialize_redaction() {
     assert_eq!(ev.content.reason.as_deref(), Some("being very unfriendly"));
     assert_eq!(ev.event_id, "$h29iv0s8:example.com");
     assert_eq!(ev.redacts, "$nomore:example.com");
+    assert_eq!(ev.redacts(&RoomVersionId::V1), "$nomore:example.com");
     assert_eq!(ev.origin_server_ts, MilliSecondsSinceUnixEpoch(uint!(1)));
     assert_eq!(ev.room_id, "!roomid:room.com");
     assert_eq!(ev.sender, "@carl:example.com");
     assert!(ev.unsigned.is_empty());
 }
+
+#[test]
+fn cast_raw_redaction() {
+    let json_data = json!({
+        "content": {
+            "reason": "being very unfriendly"
+        },
+        "redacts": "$nomore:example.com",
+        "event_id": "$h29iv0s8:example.com",
+        "sender": "@carl:example.com",
+        "origin_server_ts": 1,
+        "room_id": "!roomid:room.com",
+        "type": "m.room.redaction"
+    });
+    let raw = Raw::<AnyMessageLikeEvent>::from_value(&json_data).unwrap();
+
+    assert_eq!(
+        raw.get_field::<MessageLikeEventType>("type").unwrap(),
+        Some(MessageLikeEventType::RoomRedaction)
+    );
+
+    let ev = assert_matches!(
+        raw.cast_ref::<RoomRedactionEvent>().deserialize(),
+        Ok(RoomRedactionEvent::Original(ev)) => ev
+    );
+    assert_eq!(ev.content.reason.as_deref(), Some("being very unfriendly"));
+
+    let raw = raw.cast::<RoomRedactionEvent>();
+    assert_eq!(raw.json().get(), json_data.to_string());
+    let ev = assert_matches!(raw.deserialize(), Ok(RoomRedactionEvent::Original(ev)) => ev);
+    assert_eq!(ev.redacts, "$nomore:example.com");
+}
+
+#[test]
+fn redaction_common_fields() {
+    let json_data = json!({
+        "content": {
+            "reason": "being very unfriendly"
+        },
+        "redacts": "$nomore:example.com",
+        "event_id": "$h29iv0s8:example.com",
+        "sender": "@carl:example.com",
+        "origin_server_ts": 1,
+        "room_id": "!roomid:room.com",
+        "type": "m.room.redaction"
+    });
+
+    let ev = from_json_value::<AnyMessageLikeEvent>(json_data).unwrap();
+    assert_eq!(ev.event_id(), "$h29iv0s8:example.com");
+    assert_eq!(ev.sender(), "@carl:example.com");
+    assert_eq!(ev.origin_server_ts(), MilliSecondsSinceUnixEpoch(uint!(1)));
+    assert_eq!(ev.room_id(), "!roomid:room.com");
+}
+
+#[test]
+fn redact_message_like_event() {
+    let json_data = json!({
+        "content": {
+            "body": "spam",
+            "msgtype": "m.text"
+        },
+        "event_id": "$spam:example.com",
+        "sender": "@spammer:example.com",
+        "origin_server_ts": 1,
+        "room_id": "!roomid:room.com",
+        "type": "m.room.message"
+    });
+    let ev = from_json_value::<AnyMessageLikeEvent>(json_data).unwrap();
+
+    let redaction = ev.redact(
+        Some("spam".to_owned()),
+        user_id!("@moderator:example.com"),
+        event_id!("$redaction:example.com").to_owned(),
+        &RoomVersionId::V10,
+    );
+    assert_eq!(redaction.event_id, "$redaction:example.com");
+    assert_eq!(redaction.sender, "@moderator:example.com");
+    assert_eq!(redaction.room_id, "!roomid:room.com");
+    assert_eq!(redaction.redacts, "$spam:example.com");
+    assert_eq!(redaction.content.reason.as_deref(), Some("spam"));
+    assert_eq!(redaction.content.redacts, None);
+
+    let redaction = ev.redact(
+        None,
+        user_id!("@moderator:example.com"),
+        event_id!("$redaction:example.com").to_owned(),
+        &RoomVersionId::V11,
+    );
+    assert_eq!(redaction.redacts, "$spam:example.com");
+    assert_eq!(redaction.content.redacts.as_deref().unwrap(), "$spam:example.com");
+    assert_eq!(redaction.content.reason, None);
+
+    let redaction = ev.redact(
+        None,
+        user_id!("@moderator:example.com"),
+        event_id!("$redaction:example.com").to_owned(),
+        &RoomVersionId::try_from("io.ruma.1").unwrap(),
+    );
+    assert_eq!(redaction.redacts, "$spam:example.com");
+    assert_eq!(redaction.content.redacts, None);
+}
+
+#[test]
+fn serialize_v11_redaction_content() {
+    let content = RoomRedactionEventContent::for_room_version(
+        event_id!("$nomore:example.com").to_owned(),
+        &RoomVersionId::V11,
+    );
+
+    let actual = to_json_value(content).unwrap();
+    let expected = json!({
+        "redacts": "$nomore:example.com",
+    });
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn serialize_v1_redaction_content() {
+    let content = RoomRedactionEventContent::for_room_version(
+        event_id!("$nomore:example.com").to_owned(),
+        &RoomVersionId::V1,
+    );
+
+    let actual = to_json_value(content).unwrap();
+    let expected = json!({});
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn deserialize_v11_redaction() {
+    let json_data = json!({
+        "content": {
+            "redacts": "$nomore:example.com",
+            "reason": "being very unfriendly"
+        },
+        "redacts": "$nomore:example.com",
+        "event_id": "$h29iv0s8:example.com",
+        "sender": "@carl:example.com",
+        "origin_server_ts": 1,
+        "room_id": "!roomid:room.com",
+        "type": "m.room.redaction"
+    });
+
+    let ev = assert_matches!(
+        from_json_value::<AnyMessageLikeEvent>(json_data),
+        Ok(AnyMessageLikeEvent::RoomRedaction(RoomRedactionEvent::Original(ev))) => ev
+    );
+    assert_eq!(ev.content.reason.as_deref(), Some("being very unfriendly"));
+    assert_eq!(ev.redacts, "$nomore:example.com");
+    assert_eq!(ev.redacts(&RoomVersionId::V11), "$nomore:example.com");
+
+    let content = to_json_value(&ev.content).unwrap();
+    assert_eq!(
+        content,
+        json!({
+            "redacts": "$nomore:example.com",
+            "reason": "being very unfriendly"
+        })
+    );
+}
+
+#[test]
+fn deserialize_redaction_without_top_level_redacts() {
+    let json_data = json!({
+        "content": {
+            "redacts": "$nomore:example.com"
+        },
+        "event_id": "$h29iv0s8:example.com",
+        "sender": "@carl:example.com",
+        "origin_server_ts": 1,
+        "room_id": "!roomid:room.com",
+        "type": "m.room.redaction"
+    });
+
+    from_json_value::<RoomRedactionEvent>(json_data).unwrap_err();
+}
@@ -3,9 +3,10 @@ use assign::assign;
 use ruma_common::{
     event_id,
     events::{
-        relation::{InReplyTo, Replacement, Thread},
+        relation::{InReplyTo, Reference, Replacement, Thread},
         room::message::{MessageType, Relation, RoomMessageEventContent},
     },
+    IdParseError, RoomVersionId,
 };
 use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
@@ -249,3 +250,35 @@ fn thread_unstable_deserialize() {
     assert_eq!(thread.in_reply_to.unwrap().event_id, "$latesteventid");
     assert!(!thread.is_falling_back);
 }
+
+#[test]
+fn reference_try_new() {
+    let original_id = event_id!("$h29iv0s8:example.com");
+    let v3_id = event_id!("$acR1l0raoZnm60CBwAVgqbZqoO/mYU81xysh1u7XcJk");
+    let v4_id = event_id!("$Rqnc-F-dvnEYJTyHq_iKxU2bZ1CI92-kuZq3a5lr5Zg");
+
+    let reference = Reference::try_new(original_id.to_owned(), &RoomVersionId::V1).unwrap();
+    assert_eq!(reference.event_id, original_id);
+    assert_eq!(
+        Reference::try_new(v3_id.to_owned(), &RoomVersionId::V1).unwrap_err(),
+        IdParseError::InvalidCharacters
+    );
+
+    let reference = Reference::try_new(v3_id.to_owned(), &RoomVersionId::V3).unwrap();
+    assert_eq!(reference.event_id, v3_id);
+    assert_eq!(
+        Reference::try_new(original_id.to_owned(), &RoomVersionId::V3).unwrap_err(),
+        IdParseError::InvalidCharacters
+    );
+    assert_eq!(
+        Reference::try_new(v4_id.to_owned(), &RoomVersionId::V3).unwrap_err(),
+        IdParseError::InvalidCharacters
+    );
+
+    let reference = Reference::try_new(v4_id.to_owned(), &RoomVersionId::V10).unwrap();
+    assert_eq!(reference.event_id, v4_id);
+    assert_eq!(
+        Reference::try_new(v3_id.to_owned(), &RoomVersionId::V10).unwrap_err(),
+        IdParseError::InvalidCharacters
+    );
+}
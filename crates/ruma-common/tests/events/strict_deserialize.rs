@@ -0,0 +1,90 @@
+#![cfg(feature = "strict-deserialize")]
+
+use assert_matches::assert_matches;
+use ruma_common::{
+    events::{
+        room::{
+            message::{MessageType, RoomMessageEventContent},
+            topic::RoomTopicEventContent,
+        },
+        AnyStateEvent, AnyTimelineEvent,
+    },
+    serde::Raw,
+};
+use serde_json::{json, Value as JsonValue};
+
+fn raw<T>(json: JsonValue) -> Raw<T> {
+    Raw::from_value(&json).unwrap()
+}
+
+#[test]
+fn strict_known_content_without_extra_field() {
+    let content = raw::<RoomTopicEventContent>(json!({ "topic": "Hello" }))
+        .deserialize_deny_unknown_fields()
+        .unwrap();
+    assert_eq!(content.topic, "Hello");
+}
+
+#[test]
+fn strict_known_content_with_extra_field() {
+    let raw = raw::<RoomTopicEventContent>(json!({ "topic": "Hello", "dev.ruma.extra": true }));
+
+    let err = raw.deserialize_deny_unknown_fields().unwrap_err();
+    assert!(err.to_string().contains("unknown field `dev.ruma.extra`"), "{err}");
+
+    // The extra field is ignored by default.
+    assert_eq!(raw.deserialize().unwrap().topic, "Hello");
+}
+
+#[test]
+fn strict_custom_content_with_extra_fields() {
+    let content = raw::<RoomMessageEventContent>(json!({
+        "msgtype": "dev.ruma.custom",
+        "body": "Hello",
+        "dev.ruma.extra": true,
+    }))
+    .deserialize_deny_unknown_fields()
+    .unwrap();
+
+    assert_eq!(content.msgtype.msgtype(), "dev.ruma.custom");
+    assert_eq!(content.msgtype.data()["dev.ruma.extra"], true);
+    assert_matches!(content.msgtype, MessageType::_Custom(_));
+}
+
+#[test]
+fn strict_event_with_extra_content_field() {
+    let raw = raw::<AnyTimelineEvent>(json!({
+        "content": { "topic": "Hello", "dev.ruma.extra": true },
+        "event_id": "$h29iv0s8:example.com",
+        "origin_server_ts": 1,
+        "room_id": "!roomid:room.com",
+        "sender": "@carl:example.com",
+        "state_key": "",
+        "type": "m.room.topic",
+    }));
+
+    let err = raw.deserialize_deny_unknown_fields().unwrap_err();
+    assert!(err.to_string().contains("unknown field `dev.ruma.extra`"), "{err}");
+
+    // The extra field is ignored by default.
+    assert_matches!(
+        raw.deserialize().unwrap(),
+        AnyTimelineEvent::State(AnyStateEvent::RoomTopic(_))
+    );
+}
+
+#[test]
+fn strict_event_with_custom_content() {
+    let event = raw::<AnyTimelineEvent>(json!({
+        "content": { "dev.ruma.extra": true },
+        "event_id": "$h29iv0s8:example.com",
+        "origin_server_ts": 1,
+        "room_id": "!roomid:room.com",
+        "sender": "@carl:example.com",
+        "type": "dev.ruma.custom",
+    }))
+    .deserialize_deny_unknown_fields()
+    .unwrap();
+
+    assert_eq!(event.event_type().to_string(), "dev.ruma.custom");
+}
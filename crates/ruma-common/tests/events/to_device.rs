@@ -1,7 +1,13 @@
+use assert_matches::assert_matches;
 use ruma_common::{
-    events::room_key::ToDeviceRoomKeyEventContent, room_id, EventEncryptionAlgorithm,
+    events::{
+        key::verification::start::{StartMethod, ToDeviceKeyVerificationStartEventContent},
+        room_key::ToDeviceRoomKeyEventContent,
+        AnyToDeviceEvent, ToDeviceEvent,
+    },
+    room_id, user_id, EventEncryptionAlgorithm,
 };
-use serde_json::{json, to_value as to_json_value};
+use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
 #[test]
 fn serialization() {
@@ -22,3 +28,37 @@ fn serialization() {
         })
     );
 }
+
+#[test]
+fn verification_start_event_serialization() {
+    let event = ToDeviceEvent {
+        content: ToDeviceKeyVerificationStartEventContent::with_sas_v1("123".into(), "456".into()),
+        sender: user_id!("@alice:example.org").to_owned(),
+    };
+
+    let json = to_json_value(&event).unwrap();
+    assert_eq!(
+        json,
+        json!({
+            "content": {
+                "from_device": "123",
+                "transaction_id": "456",
+                "method": "m.sas.v1",
+                "key_agreement_protocols": ["curve25519-hkdf-sha256", "curve25519"],
+                "hashes": ["sha256"],
+                "message_authentication_codes": ["hkdf-hmac-sha256.v2", "hkdf-hmac-sha256"],
+                "short_authentication_string": ["decimal", "emoji"],
+            },
+            "sender": "@alice:example.org",
+            "type": "m.key.verification.start",
+        })
+    );
+
+    let event = assert_matches!(
+        from_json_value::<AnyToDeviceEvent>(json),
+        Ok(AnyToDeviceEvent::KeyVerificationStart(event)) => event
+    );
+    assert_eq!(event.sender, "@alice:example.org");
+    assert_eq!(event.content.transaction_id, "456");
+    assert_matches!(event.content.method, StartMethod::SasV1(_));
+}
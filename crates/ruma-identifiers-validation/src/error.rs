@@ -51,6 +51,11 @@ pub enum Error {
     #[error("required colon is missing")]
     MissingColon,
 
+    /// The string is missing the delimiter between its parts, like the slash between the user ID
+    /// and the device ID of a `UserDevice`.
+    #[error("required delimiter is missing")]
+    MissingDelimiter,
+
     /// The ID is missing the correct leading sigil.
     #[error("leading sigil is incorrect or missing")]
     MissingLeadingSigil,
@@ -2,7 +2,7 @@
 
 use syn::{
     parse::{Parse, ParseStream},
-    Ident, Token, Type,
+    Ident, LitInt, LitStr, Token, Type,
 };
 
 mod kw {
@@ -13,14 +13,18 @@ mod kw {
     syn::custom_keyword!(query_map);
     syn::custom_keyword!(header);
     syn::custom_keyword!(error);
+    syn::custom_keyword!(max_body);
     syn::custom_keyword!(manual_body_serde);
+    syn::custom_keyword!(check_content_type);
+    syn::custom_keyword!(validate);
+    syn::custom_keyword!(style);
 }
 
 pub enum RequestMeta {
     NewtypeBody,
     RawBody,
-    Path,
-    Query,
+    Path(Option<syn::Path>),
+    Query(QueryStyle),
     QueryMap,
     Header(Ident),
 }
@@ -36,10 +40,25 @@ impl Parse for RequestMeta {
             Ok(Self::RawBody)
         } else if lookahead.peek(kw::path) {
             let _: kw::path = input.parse()?;
-            Ok(Self::Path)
+            if input.is_empty() {
+                return Ok(Self::Path(None));
+            }
+
+            let _: Token![,] = input.parse()?;
+            let _: kw::validate = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let validate: LitStr = input.parse()?;
+            validate.parse().map(|path| Self::Path(Some(path)))
         } else if lookahead.peek(kw::query) {
             let _: kw::query = input.parse()?;
-            Ok(Self::Query)
+            if input.is_empty() {
+                return Ok(Self::Query(QueryStyle::Repeat));
+            }
+
+            let _: Token![,] = input.parse()?;
+            let _: kw::style = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            input.parse().map(Self::Query)
         } else if lookahead.peek(kw::query_map) {
             let _: kw::query_map = input.parse()?;
             Ok(Self::QueryMap)
@@ -53,8 +72,33 @@ impl Parse for RequestMeta {
     }
 }
 
+/// How a list is represented in the query string.
+#[derive(Clone, Copy)]
+pub enum QueryStyle {
+    /// The key is repeated for every value: `key=val1&key=val2`.
+    Repeat,
+
+    /// The values are joined with commas: `key=val1,val2`.
+    Comma,
+}
+
+impl Parse for QueryStyle {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let style: LitStr = input.parse()?;
+        match style.value().as_str() {
+            "repeat" => Ok(Self::Repeat),
+            "comma" => Ok(Self::Comma),
+            _ => Err(syn::Error::new_spanned(
+                style,
+                "unknown query style, expected \"repeat\" or \"comma\"",
+            )),
+        }
+    }
+}
+
 pub enum DeriveRequestMeta {
-    Error(Type),
+    Error(Box<Type>),
+    MaxBody(LitInt),
 }
 
 impl Parse for DeriveRequestMeta {
@@ -63,7 +107,11 @@ impl Parse for DeriveRequestMeta {
         if lookahead.peek(kw::error) {
             let _: kw::error = input.parse()?;
             let _: Token![=] = input.parse()?;
-            input.parse().map(Self::Error)
+            input.parse().map(|ty| Self::Error(Box::new(ty)))
+        } else if lookahead.peek(kw::max_body) {
+            let _: kw::max_body = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            input.parse().map(Self::MaxBody)
         } else {
             Err(lookahead.error())
         }
@@ -99,6 +147,7 @@ impl Parse for ResponseMeta {
 pub enum DeriveResponseMeta {
     ManualBodySerde,
     Error(Type),
+    CheckContentType,
 }
 
 impl Parse for DeriveResponseMeta {
@@ -111,6 +160,9 @@ impl Parse for DeriveResponseMeta {
             let _: kw::error = input.parse()?;
             let _: Token![=] = input.parse()?;
             input.parse().map(Self::Error)
+        } else if lookahead.peek(kw::check_content_type) {
+            let _: kw::check_content_type = input.parse()?;
+            Ok(Self::CheckContentType)
         } else {
             Err(lookahead.error())
         }
@@ -7,7 +7,7 @@ use syn::{
 };
 
 use super::{
-    attribute::{DeriveRequestMeta, RequestMeta},
+    attribute::{DeriveRequestMeta, QueryStyle, RequestMeta},
     ensure_feature_presence,
 };
 use crate::util::import_ruma_common;
@@ -21,10 +21,17 @@ pub fn expand_request(attr: RequestAttr, item: ItemStruct) -> TokenStream {
 
     let maybe_feature_error = ensure_feature_presence().map(syn::Error::to_compile_error);
 
-    let error_ty = attr.0.first().map_or_else(
-        || quote! { #ruma_common::api::error::MatrixError },
-        |DeriveRequestMeta::Error(ty)| quote! { #ty },
-    );
+    let mut error_ty = quote! { #ruma_common::api::error::MatrixError };
+    let mut max_body = None;
+
+    for meta in attr.0 {
+        match meta {
+            DeriveRequestMeta::Error(ty) => error_ty = quote! { #ty },
+            DeriveRequestMeta::MaxBody(max) => {
+                max_body = Some(quote! { #[ruma_api(max_body = #max)] });
+            }
+        }
+    }
 
     quote! {
         #maybe_feature_error
@@ -32,6 +39,7 @@ pub fn expand_request(attr: RequestAttr, item: ItemStruct) -> TokenStream {
         #[derive(Clone, Debug, #ruma_macros::Request, #ruma_common::serde::_FakeDeriveSerde)]
         #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
         #[ruma_api(error = #error_ty)]
+        #max_body
         #item
     }
 }
@@ -53,6 +61,7 @@ pub fn expand_derive_request(input: DeriveInput) -> syn::Result<TokenStream> {
     let fields = fields.into_iter().map(RequestField::try_from).collect::<syn::Result<_>>()?;
 
     let mut error_ty = None;
+    let mut max_body = None;
 
     for attr in input.attrs {
         if !attr.path.is_ident("ruma_api") {
@@ -63,7 +72,8 @@ pub fn expand_derive_request(input: DeriveInput) -> syn::Result<TokenStream> {
             attr.parse_args_with(Punctuated::<DeriveRequestMeta, Token![,]>::parse_terminated)?;
         for meta in metas {
             match meta {
-                DeriveRequestMeta::Error(t) => error_ty = Some(t),
+                DeriveRequestMeta::Error(t) => error_ty = Some(*t),
+                DeriveRequestMeta::MaxBody(max) => max_body = Some(max.base10_parse()?),
             }
         }
     }
@@ -73,6 +83,7 @@ pub fn expand_derive_request(input: DeriveInput) -> syn::Result<TokenStream> {
         generics: input.generics,
         fields,
         error_ty: error_ty.expect("missing error_ty attribute"),
+        max_body,
     };
 
     let ruma_common = import_ruma_common();
@@ -96,6 +107,7 @@ struct Request {
     fields: Vec<RequestField>,
 
     error_ty: Type,
+    max_body: Option<usize>,
 }
 
 impl Request {
@@ -118,11 +130,11 @@ impl Request {
     }
 
     fn has_path_fields(&self) -> bool {
-        self.fields.iter().any(|f| matches!(&f.kind, RequestFieldKind::Path))
+        self.fields.iter().any(|f| matches!(&f.kind, RequestFieldKind::Path(_)))
     }
 
     fn has_query_fields(&self) -> bool {
-        self.fields.iter().any(|f| matches!(&f.kind, RequestFieldKind::Query))
+        self.fields.iter().any(|f| matches!(&f.kind, RequestFieldKind::Query(_)))
     }
 
     fn header_fields(&self) -> impl Iterator<Item = (&Field, &Ident)> {
@@ -164,7 +176,17 @@ impl Request {
             let field = Field { ident: None, colon_token: None, ..f.clone() };
             Some(quote! { (#field); })
         } else if self.has_query_fields() {
-            let fields = self.fields.iter().filter_map(RequestField::as_query_field);
+            let comma_separated_path = quote! { #ruma_common::serde::comma_separated }.to_string();
+            let fields =
+                self.fields.iter().filter_map(RequestField::as_query_field_with_style).map(
+                    |(field, style)| match style {
+                        QueryStyle::Repeat => quote! { #field },
+                        QueryStyle::Comma => quote! {
+                            #[serde(with = #comma_separated_path)]
+                            #field
+                        },
+                    },
+                );
             Some(quote! { { #(#fields),* } })
         } else {
             None
@@ -232,7 +254,7 @@ impl Request {
 
         let has_body_fields = self.fields.iter().any(|f| matches!(&f.kind, RequestFieldKind::Body));
         let has_query_fields =
-            self.fields.iter().any(|f| matches!(&f.kind, RequestFieldKind::Query));
+            self.fields.iter().any(|f| matches!(&f.kind, RequestFieldKind::Query(_)));
 
         if has_newtype_body_field && has_body_fields {
             return Err(syn::Error::new_spanned(
@@ -299,10 +321,14 @@ pub(super) enum RequestFieldKind {
     RawBody,
 
     /// Data that appears in the URL path.
-    Path,
+    ///
+    /// Contains the path of the function used to validate the value, if any.
+    Path(Option<syn::Path>),
 
     /// Data that appears in the query string.
-    Query,
+    ///
+    /// Contains how lists are represented in the query string.
+    Query(QueryStyle),
 
     /// Data that appears in the query string as dynamic key-value pairs.
     QueryMap,
@@ -314,8 +340,8 @@ impl RequestField {
         let kind = match kind_attr {
             Some(RequestMeta::NewtypeBody) => RequestFieldKind::NewtypeBody,
             Some(RequestMeta::RawBody) => RequestFieldKind::RawBody,
-            Some(RequestMeta::Path) => RequestFieldKind::Path,
-            Some(RequestMeta::Query) => RequestFieldKind::Query,
+            Some(RequestMeta::Path(validate)) => RequestFieldKind::Path(validate),
+            Some(RequestMeta::Query(style)) => RequestFieldKind::Query(style),
             Some(RequestMeta::QueryMap) => RequestFieldKind::QueryMap,
             Some(RequestMeta::Header(header)) => RequestFieldKind::Header(header),
             None => RequestFieldKind::Body,
@@ -343,7 +369,16 @@ impl RequestField {
     /// Return the contained field if this request field is a path kind.
     pub fn as_path_field(&self) -> Option<&Field> {
         match &self.kind {
-            RequestFieldKind::Path => Some(&self.inner),
+            RequestFieldKind::Path(_) => Some(&self.inner),
+            _ => None,
+        }
+    }
+
+    /// Return the contained field and validation function if this request field is a path kind
+    /// with a validation function.
+    pub fn as_validated_path_field(&self) -> Option<(&Field, &syn::Path)> {
+        match &self.kind {
+            RequestFieldKind::Path(Some(validate)) => Some((&self.inner, validate)),
             _ => None,
         }
     }
@@ -351,7 +386,15 @@ impl RequestField {
     /// Return the contained field if this request field is a query kind.
     pub fn as_query_field(&self) -> Option<&Field> {
         match &self.kind {
-            RequestFieldKind::Query => Some(&self.inner),
+            RequestFieldKind::Query(_) => Some(&self.inner),
+            _ => None,
+        }
+    }
+
+    /// Return the contained field and its query style if this request field is a query kind.
+    pub fn as_query_field_with_style(&self) -> Option<(&Field, QueryStyle)> {
+        match &self.kind {
+            RequestFieldKind::Query(style) => Some((&self.inner, *style)),
             _ => None,
         }
     }
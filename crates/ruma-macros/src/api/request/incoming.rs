@@ -17,13 +17,23 @@ impl Request {
         // If we get errors about missing fields in Request for a path field look here.
         let (parse_request_path, path_vars) = if self.has_path_fields() {
             let path_vars: Vec<_> = self.path_fields().filter_map(|f| f.ident.as_ref()).collect();
+            let path_tys = self.path_fields().map(|f| &f.ty);
+            let validate_path_vars =
+                self.fields.iter().filter_map(RequestField::as_validated_path_field).map(
+                    |(field, validate)| {
+                        let field_name = &field.ident;
+                        quote! { #validate(&#field_name)?; }
+                    },
+                );
 
             let parse_request_path = quote! {
-                let (#(#path_vars,)*) = #serde::Deserialize::deserialize(
+                let (#(#path_vars,)*): (#(#path_tys,)*) = #serde::Deserialize::deserialize(
                     #serde::de::value::SeqDeserializer::<_, #serde::de::value::Error>::new(
                         path_args.iter().map(::std::convert::AsRef::as_ref)
                     )
                 )?;
+
+                #(#validate_path_vars)*
             };
 
             (parse_request_path, quote! { #(#path_vars,)* })
@@ -102,19 +102,53 @@ impl Request {
             req_headers.extend(METADATA.authorization_header(access_token)?);
         });
 
-        let request_body = if let Some(field) = self.raw_body_field() {
-            let field_name = field.ident.as_ref().expect("expected field to have an identifier");
-            quote! { #ruma_common::serde::slice_to_buf(&self.#field_name) }
-        } else if self.has_body_fields() {
-            let initializers = struct_init_fields(self.body_fields(), quote! { self });
-
-            quote! {
-                #ruma_common::serde::json_to_buf(&RequestBody { #initializers })?
+        let request_body = |buf_ty: TokenStream| {
+            if let Some(field) = self.raw_body_field() {
+                let field_name =
+                    field.ident.as_ref().expect("expected field to have an identifier");
+                quote! { #ruma_common::serde::slice_to_buf::<#buf_ty>(&self.#field_name) }
+            } else if self.has_body_fields() {
+                let initializers = struct_init_fields(self.body_fields(), quote! { self });
+
+                quote! {
+                    #ruma_common::serde::json_to_buf::<#buf_ty, _>(&RequestBody { #initializers })?
+                }
+            } else {
+                quote! { METADATA.empty_request_body::<#buf_ty>() }
             }
+        };
+
+        // When the size of the body is limited, serialize it to an intermediate buffer to be able
+        // to check its length, since `T` only implements `BufMut`.
+        let request_body = if let Some(max_body) = self.max_body {
+            let body = request_body(quote! { #bytes::BytesMut });
+
+            quote! {{
+                let body = #body;
+                if body.len() > #max_body {
+                    return Err(#ruma_common::api::error::IntoHttpError::BodyTooLarge {
+                        len: body.len(),
+                        max: #max_body,
+                    });
+                }
+
+                #ruma_common::serde::slice_to_buf(&body)
+            }}
         } else {
-            quote! { METADATA.empty_request_body::<T>() }
+            request_body(quote! { T })
         };
 
+        // Only the length of a raw body is known without serializing the request.
+        let estimated_body_len = self.raw_body_field().map(|field| {
+            let field_name = field.ident.as_ref().expect("expected field to have an identifier");
+            quote! {
+                fn estimated_body_len(&self) -> ::std::option::Option<::std::primitive::usize> {
+                    let body: &[::std::primitive::u8] = &self.#field_name;
+                    ::std::option::Option::Some(body.len())
+                }
+            }
+        });
+
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
         quote! {
@@ -149,6 +183,8 @@ impl Request {
 
                     Ok(http_request)
                 }
+
+                #estimated_body_len
             }
         }
     }
@@ -24,14 +24,18 @@ pub fn expand_response(attr: ResponseAttr, item: ItemStruct) -> TokenStream {
 
     let maybe_feature_error = ensure_feature_presence().map(syn::Error::to_compile_error);
 
-    let error_ty = attr
-        .0
-        .iter()
-        .find_map(|a| match a {
-            DeriveResponseMeta::Error(ty) => Some(quote! { #ty }),
-            _ => None,
-        })
-        .unwrap_or_else(|| quote! { #ruma_common::api::error::MatrixError });
+    let mut error_ty = quote! { #ruma_common::api::error::MatrixError };
+    let mut check_content_type = None;
+
+    for meta in attr.0 {
+        match meta {
+            DeriveResponseMeta::Error(ty) => error_ty = quote! { #ty },
+            DeriveResponseMeta::CheckContentType => {
+                check_content_type = Some(quote! { #[ruma_api(check_content_type)] });
+            }
+            DeriveResponseMeta::ManualBodySerde => {}
+        }
+    }
 
     quote! {
         #maybe_feature_error
@@ -39,6 +43,7 @@ pub fn expand_response(attr: ResponseAttr, item: ItemStruct) -> TokenStream {
         #[derive(Clone, Debug, #ruma_macros::Response, #ruma_common::serde::_FakeDeriveSerde)]
         #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
         #[ruma_api(error = #error_ty)]
+        #check_content_type
         #item
     }
 }
@@ -60,6 +65,7 @@ pub fn expand_derive_response(input: DeriveInput) -> syn::Result<TokenStream> {
     let fields = fields.into_iter().map(ResponseField::try_from).collect::<syn::Result<_>>()?;
     let mut manual_body_serde = false;
     let mut error_ty = None;
+    let mut check_content_type = false;
     for attr in input.attrs {
         if !attr.path.is_ident("ruma_api") {
             continue;
@@ -71,6 +77,7 @@ pub fn expand_derive_response(input: DeriveInput) -> syn::Result<TokenStream> {
             match meta {
                 DeriveResponseMeta::ManualBodySerde => manual_body_serde = true,
                 DeriveResponseMeta::Error(t) => error_ty = Some(t),
+                DeriveResponseMeta::CheckContentType => check_content_type = true,
             }
         }
     }
@@ -81,6 +88,7 @@ pub fn expand_derive_response(input: DeriveInput) -> syn::Result<TokenStream> {
         fields,
         manual_body_serde,
         error_ty: error_ty.unwrap(),
+        check_content_type,
     };
 
     response.check()?;
@@ -93,6 +101,7 @@ struct Response {
     fields: Vec<ResponseField>,
     manual_body_serde: bool,
     error_ty: Type,
+    check_content_type: bool,
 }
 
 impl Response {
@@ -16,7 +16,15 @@ impl Response {
         });
 
         let typed_response_body_decl = self.has_body_fields().then(|| {
+            let check_content_type = self.check_content_type.then(|| {
+                quote! {
+                    #ruma_common::api::error::_check_json_content_type(response.headers())?;
+                }
+            });
+
             quote! {
+                #check_content_type
+
                 let response_body: ResponseBody = {
                     let body = ::std::convert::AsRef::<[::std::primitive::u8]>::as_ref(
                         response.body(),
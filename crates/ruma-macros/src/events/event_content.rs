@@ -360,7 +360,7 @@ pub fn expand_event_content(
     )
     .unwrap_or_else(syn::Error::into_compile_error);
     let static_event_content_impl =
-        generate_static_event_content_impl(ident, &event_type, ruma_common);
+        generate_static_event_content_impl(ident, &event_type, &aliases, ruma_common);
     let type_aliases = event_kind.map(|k| {
         generate_event_type_aliases(k, ident, &input.vis, &event_type.value(), ruma_common)
             .unwrap_or_else(syn::Error::into_compile_error)
@@ -456,7 +456,7 @@ fn generate_redacted_event_content<'a>(
     .unwrap_or_else(syn::Error::into_compile_error);
 
     let static_event_content_impl =
-        generate_static_event_content_impl(&redacted_ident, event_type, ruma_common);
+        generate_static_event_content_impl(&redacted_ident, event_type, aliases, ruma_common);
 
     Ok(quote! {
         // this is the non redacted event content's impl
@@ -608,8 +608,12 @@ fn generate_possibly_redacted_event_content<'a>(
         )
         .unwrap_or_else(syn::Error::into_compile_error);
 
-        let static_event_content_impl =
-            generate_static_event_content_impl(&possibly_redacted_ident, event_type, ruma_common);
+        let static_event_content_impl = generate_static_event_content_impl(
+            &possibly_redacted_ident,
+            event_type,
+            aliases,
+            ruma_common,
+        );
 
         Ok(quote! {
             #[doc = #doc]
@@ -977,11 +981,13 @@ fn generate_event_content_impl<'a>(
 fn generate_static_event_content_impl(
     ident: &Ident,
     event_type: &LitStr,
+    aliases: &[LitStr],
     ruma_common: &TokenStream,
 ) -> TokenStream {
     quote! {
         impl #ruma_common::events::StaticEventContent for #ident {
             const TYPE: &'static ::std::primitive::str = #event_type;
+            const ALIASES: &'static [&'static ::std::primitive::str] = &[#(#aliases,)*];
         }
     }
 }
@@ -43,7 +43,7 @@ use self::{
         deserialize_from_cow_str::expand_deserialize_from_cow_str,
         display_as_ref_str::expand_display_as_ref_str,
         enum_as_ref_str::expand_enum_as_ref_str,
-        enum_from_string::expand_enum_from_string,
+        enum_from_string::{expand_enum_from_string, expand_from_str_from_string},
         eq_as_ref_str::expand_partial_eq_as_ref_str,
         ord_as_ref_str::{expand_ord_as_ref_str, expand_partial_ord_as_ref_str},
         serialize_as_ref_str::expand_serialize_as_ref_str,
@@ -340,11 +340,14 @@ pub fn derive_partial_eq_as_ref_str(input: TokenStream) -> TokenStream {
 
 /// Shorthand for the derives `AsRefStr`, `FromString`, `DisplayAsRefStr`, `DebugAsRefStr`,
 /// `SerializeAsRefStr` and `DeserializeFromCowStr`.
+///
+/// It also implements `FromStr` using the `From<&str>` implementation of the type.
 #[proc_macro_derive(StringEnum, attributes(ruma_enum))]
 pub fn derive_string_enum(input: TokenStream) -> TokenStream {
     fn expand_all(input: ItemEnum) -> syn::Result<proc_macro2::TokenStream> {
         let as_ref_str_impl = expand_enum_as_ref_str(&input)?;
         let from_string_impl = expand_enum_from_string(&input)?;
+        let from_str_impl = expand_from_str_from_string(&input.ident)?;
         let as_str_impl = expand_as_str_as_ref_str(&input.ident)?;
         let display_impl = expand_display_as_ref_str(&input.ident)?;
         let debug_impl = expand_debug_as_ref_str(&input.ident)?;
@@ -354,6 +357,7 @@ pub fn derive_string_enum(input: TokenStream) -> TokenStream {
         Ok(quote! {
             #as_ref_str_impl
             #from_string_impl
+            #from_str_impl
             #as_str_impl
             #display_impl
             #debug_impl
@@ -1,4 +1,4 @@
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, ToTokens};
 use syn::{Fields, FieldsNamed, FieldsUnnamed, ItemEnum};
 
@@ -93,3 +93,17 @@ pub fn expand_enum_from_string(input: &ItemEnum) -> syn::Result<TokenStream> {
         }
     })
 }
+
+pub fn expand_from_str_from_string(ident: &Ident) -> syn::Result<TokenStream> {
+    Ok(quote! {
+        #[automatically_derived]
+        #[allow(deprecated)]
+        impl ::std::str::FromStr for #ident {
+            type Err = ::std::convert::Infallible;
+
+            fn from_str(s: &::std::primitive::str) -> ::std::result::Result<Self, Self::Err> {
+                ::std::result::Result::Ok(::std::convert::From::from(s))
+            }
+        }
+    })
+}
@@ -546,19 +546,8 @@ pub fn verify_event(
 ) -> Result<Verified, Error> {
     let redacted = redact(object.clone(), version, None)?;
 
-    let hash = match object.get("hashes") {
-        Some(hashes_value) => match hashes_value {
-            CanonicalJsonValue::Object(hashes) => match hashes.get("sha256") {
-                Some(hash_value) => match hash_value {
-                    CanonicalJsonValue::String(hash) => hash,
-                    _ => return Err(JsonError::not_of_type("sha256 hash", JsonType::String)),
-                },
-                None => return Err(JsonError::not_of_type("hashes", JsonType::Object)),
-            },
-            _ => return Err(JsonError::field_missing_from_object("sha256")),
-        },
-        None => return Err(JsonError::field_missing_from_object("hashes")),
-    };
+    // Make sure that the event has a content hash before checking the signatures.
+    sha256_content_hash(object)?;
 
     let signature_map = match object.get("signatures") {
         Some(CanonicalJsonValue::Object(signatures)) => signatures,
@@ -617,15 +606,53 @@ pub fn verify_event(
         }
     }
 
+    if verify_content_hash(object)? {
+        Ok(Verified::All)
+    } else {
+        Ok(Verified::Signatures)
+    }
+}
+
+/// Verifies the *content hash* of an event.
+///
+/// Recomputes the content hash of the event with [`content_hash`] and compares it to the SHA-256
+/// hash in its `hashes` field.
+///
+/// # Parameters
+///
+/// object: The JSON object of the event whose content hash should be verified.
+///
+/// # Errors
+///
+/// Returns an error if the `hashes` field or its SHA-256 hash is missing or has the wrong type, or
+/// if the event is too large.
+///
+/// Returns `Ok(false)` if the hash doesn't match, including when it isn't valid base64.
+pub fn verify_content_hash(object: &CanonicalJsonObject) -> Result<bool, Error> {
+    let hash = sha256_content_hash(object)?;
     let calculated_hash = content_hash(object)?;
 
-    if let Ok(hash) = Base64::<Standard>::parse(hash) {
-        if hash.as_bytes() == calculated_hash.as_bytes() {
-            return Ok(Verified::All);
-        }
-    }
+    Ok(matches!(
+        Base64::<Standard>::parse(hash),
+        Ok(hash) if hash.as_bytes() == calculated_hash.as_bytes()
+    ))
+}
 
-    Ok(Verified::Signatures)
+/// Get the SHA-256 content hash in the `hashes` field of an event.
+fn sha256_content_hash(object: &CanonicalJsonObject) -> Result<&str, Error> {
+    match object.get("hashes") {
+        Some(hashes_value) => match hashes_value {
+            CanonicalJsonValue::Object(hashes) => match hashes.get("sha256") {
+                Some(hash_value) => match hash_value {
+                    CanonicalJsonValue::String(hash) => Ok(hash),
+                    _ => Err(JsonError::not_of_type("sha256 hash", JsonType::String)),
+                },
+                None => Err(JsonError::not_of_type("hashes", JsonType::Object)),
+            },
+            _ => Err(JsonError::field_missing_from_object("sha256")),
+        },
+        None => Err(JsonError::field_missing_from_object("hashes")),
+    }
 }
 
 /// Internal implementation detail of the canonical JSON algorithm.
@@ -692,7 +719,7 @@ fn servers_to_check_signatures(
         | RoomVersionId::V6
         | RoomVersionId::V7 => {}
         // TODO: And for all future versions that have join_authorised_via_users_server
-        RoomVersionId::V8 | RoomVersionId::V9 | RoomVersionId::V10 => {
+        RoomVersionId::V8 | RoomVersionId::V9 | RoomVersionId::V10 | RoomVersionId::V11 => {
             if let Some(authorized_user) = object
                 .get("content")
                 .and_then(|c| c.as_object())
@@ -731,7 +758,7 @@ mod tests {
     };
     use serde_json::json;
 
-    use super::canonical_json;
+    use super::{canonical_json, verify_content_hash};
     use crate::{
         sign_json, verify_event, Ed25519KeyPair, Error, PublicKeyMap, PublicKeySet,
         VerificationError, Verified,
@@ -769,6 +796,41 @@ mod tests {
         assert_eq!(canonical_json(&object).unwrap(), canonical);
     }
 
+    #[test]
+    fn verify_content_hash_matching_and_tampered() {
+        let event = json!({
+            "auth_events": [],
+            "content": {},
+            "depth": 3,
+            "hashes": {
+                "sha256": "5jM4wQpv6lnBo7CLIghJuHdW+s2CMBJPUOGOC89ncos"
+            },
+            "origin": "domain",
+            "origin_server_ts": 1_000_000,
+            "prev_events": [],
+            "room_id": "!x:domain",
+            "sender": "@a:domain",
+            "signatures": {},
+            "type": "X",
+            "unsigned": {
+                "age_ts": 1_000_000
+            }
+        });
+        let mut object = match CanonicalJsonValue::try_from(event).unwrap() {
+            CanonicalJsonValue::Object(obj) => obj,
+            _ => unreachable!(),
+        };
+
+        // Signatures and unsigned data are not covered by the content hash.
+        assert!(verify_content_hash(&object).unwrap());
+
+        object.insert("content".into(), json!({ "body": "tampered" }).try_into().unwrap());
+        assert!(!verify_content_hash(&object).unwrap());
+
+        object.remove("hashes");
+        assert_matches!(verify_content_hash(&object), Err(Error::Json(_)));
+    }
+
     #[test]
     fn verify_event_does_not_check_signatures_for_third_party_invites() {
         let signed_event = serde_json::from_str(
@@ -48,8 +48,8 @@ use ruma_common::serde::{AsRefStr, DisplayAsRefStr};
 
 pub use error::{Error, JsonError, ParseError, VerificationError};
 pub use functions::{
-    canonical_json, content_hash, hash_and_sign_event, reference_hash, sign_json, verify_event,
-    verify_json,
+    canonical_json, content_hash, hash_and_sign_event, reference_hash, sign_json,
+    verify_content_hash, verify_event, verify_json,
 };
 pub use keys::{Ed25519KeyPair, KeyPair, PublicKeyMap, PublicKeySet};
 pub use signatures::Signature;
@@ -115,7 +115,8 @@ mod tests {
     use serde_json::{from_str as from_json_str, to_string as to_json_string};
 
     use super::{
-        canonical_json, hash_and_sign_event, sign_json, verify_event, verify_json, Ed25519KeyPair,
+        canonical_json, hash_and_sign_event, reference_hash, sign_json, verify_event, verify_json,
+        Ed25519KeyPair,
     };
 
     fn pkcs8() -> Vec<u8> {
@@ -340,6 +341,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reference_hash_minimal_event() {
+        let json = r#"{
+            "auth_events": [],
+            "content": {},
+            "depth": 3,
+            "hashes": {
+                "sha256": "5jM4wQpv6lnBo7CLIghJuHdW+s2CMBJPUOGOC89ncos"
+            },
+            "origin": "domain",
+            "origin_server_ts": 1000000,
+            "prev_events": [],
+            "room_id": "!x:domain",
+            "sender": "@a:domain",
+            "signatures": {
+                "domain": {
+                    "ed25519:1": "PxOFMn6ORll8PFSQp0IRF6037MEZt3Mfzu/ROiT/gb/ccs1G+f6Ddoswez4KntLPBI3GKCGIkhctiK37JOy2Aw"
+                }
+            },
+            "type": "X",
+            "unsigned": {
+                "age_ts": 1000000
+            }
+        }"#;
+
+        let object = from_json_str(json).unwrap();
+
+        assert_eq!(
+            reference_hash(&object, &RoomVersionId::V5).unwrap(),
+            "8yif6p8EqgoSten2BLje9ntKm720NyFLWQv9tn8memc"
+        );
+    }
+
     #[test]
     fn sign_redacted_event() {
         let key_pair = Ed25519KeyPair::from_der(&pkcs8(), "1".into()).unwrap();
@@ -40,6 +40,7 @@
 //!
 //! * `rand`
 //! * `markdown`
+//! * `strict-deserialize`
 //!
 //! # Unstable features
 //!